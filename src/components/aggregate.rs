@@ -0,0 +1,82 @@
+use crate::components::func::Func;
+use crate::components::search::Find;
+use std::sync::Arc;
+
+pub type Aggregator = Func<dyn Fn(&[Find]) -> f32 + Send + Sync>;
+
+impl Aggregator {
+    pub fn new<F>(f: F) -> Self
+    where
+        F: Fn(&[Find]) -> f32 + Send + Sync + 'static,
+    {
+        Func(Arc::new(f))
+    }
+}
+
+/// How `NekoSearch` folds the per-algorithm scores into one value.
+/// Algorithms with a weight of zero are left out of every strategy except
+/// `Custom`, which receives all results untouched.
+#[derive(Debug, Clone, Default)]
+pub enum Aggregation {
+    #[default]
+    WeightedMean,
+    WeightedGeometric,
+    Harmonic,
+    Max,
+    Min,
+    Median,
+    Custom(Aggregator),
+}
+
+impl Aggregation {
+    /// Whether raising any single score can never lower the result, which is
+    /// what cascade pruning relies on. Unknown for `Custom`.
+    pub fn is_monotone(&self) -> bool {
+        !matches!(self, Aggregation::Custom(_))
+    }
+
+    pub fn apply(&self, results: &[Find]) -> f32 {
+        let pairs: Vec<(f32, f32)> = results
+            .iter()
+            .map(|find| (find.score, find.weight))
+            .filter(|&(_, weight)| weight > 0.0)
+            .collect();
+        let total_weight: f32 = pairs.iter().map(|&(_, w)| w).sum();
+
+        let result = match self {
+            Aggregation::Custom(f) => f(results),
+            _ if pairs.is_empty() => 0.0,
+            Aggregation::WeightedMean => {
+                pairs.iter().map(|&(s, w)| s * w).sum::<f32>() / total_weight
+            }
+            Aggregation::WeightedGeometric => {
+                if pairs.iter().any(|&(s, _)| s <= 0.0) {
+                    0.0
+                } else {
+                    (pairs.iter().map(|&(s, w)| w * s.ln()).sum::<f32>() / total_weight).exp()
+                }
+            }
+            Aggregation::Harmonic => {
+                if pairs.iter().any(|&(s, _)| s <= 0.0) {
+                    0.0
+                } else {
+                    total_weight / pairs.iter().map(|&(s, w)| w / s).sum::<f32>()
+                }
+            }
+            Aggregation::Max => pairs.iter().map(|&(s, _)| s).fold(0.0, f32::max),
+            Aggregation::Min => pairs.iter().map(|&(s, _)| s).fold(1.0, f32::min),
+            Aggregation::Median => {
+                let mut scores: Vec<f32> = pairs.iter().map(|&(s, _)| s).collect();
+                scores.sort_by(f32::total_cmp);
+                let mid = scores.len() / 2;
+                if scores.len().is_multiple_of(2) {
+                    (scores[mid - 1] + scores[mid]) / 2.0
+                } else {
+                    scores[mid]
+                }
+            }
+        };
+
+        result.clamp(0.0, 1.0)
+    }
+}
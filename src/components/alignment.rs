@@ -0,0 +1,183 @@
+use crate::components::func::Func;
+use crate::components::types::Segmentation;
+use crate::components::unicode::{base, boundaries, segment};
+use std::ops::Range;
+use std::sync::Arc;
+
+pub type Substitution = Func<dyn Fn(char, char) -> f32 + Send + Sync>;
+
+impl Substitution {
+    pub fn new<F>(f: F) -> Self
+    where
+        F: Fn(char, char) -> f32 + Send + Sync + 'static,
+    {
+        Func(Arc::new(f))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Alignment {
+    /// Raw alignment score using the configured scoring scheme.
+    pub raw: f32,
+    /// Alignment score normalized into `0.0..=1.0`.
+    pub score: f32,
+    /// Byte range of the aligned region inside the first string.
+    pub span1: Range<usize>,
+    /// Byte range of the aligned region inside the second string.
+    pub span2: Range<usize>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Scoring<'a> {
+    pub match_score: f32,
+    pub mismatch_score: f32,
+    pub gap_open: f32,
+    pub gap_extend: f32,
+    pub substitution: Option<&'a Substitution>,
+    pub segmentation: Segmentation,
+}
+
+impl Scoring<'_> {
+    fn sub(&self, a: &str, b: &str) -> f32 {
+        let single = |u: &str| u.chars().nth(1).is_none();
+        match self.substitution {
+            Some(substitution) if single(a) && single(b) => substitution(base(a), base(b)),
+            _ if a == b => self.match_score,
+            _ => self.mismatch_score,
+        }
+    }
+
+    fn self_score(&self, s: &[&str]) -> f32 {
+        s.iter().map(|u| self.sub(u, u)).sum()
+    }
+}
+
+#[derive(Clone, Copy)]
+struct Cell {
+    score: f32,
+    start: (usize, usize),
+}
+
+impl Cell {
+    const NONE: Cell = Cell {
+        score: f32::NEG_INFINITY,
+        start: (0, 0),
+    };
+
+    fn best(self, other: Cell) -> Cell {
+        if other.score > self.score {
+            other
+        } else {
+            self
+        }
+    }
+
+    fn add(self, value: f32) -> Cell {
+        Cell {
+            score: self.score + value,
+            start: self.start,
+        }
+    }
+}
+
+fn byte_range(s: &str, units: Range<usize>, segmentation: Segmentation) -> Range<usize> {
+    let offsets = boundaries(s, segmentation);
+    offsets[units.start]..offsets[units.end]
+}
+
+/// Gotoh alignment with affine gaps. A gap of length `k` costs
+/// `gap_open + (k - 1) * gap_extend`. `local` selects Smith-Waterman,
+/// otherwise Needleman-Wunsch is used.
+pub(crate) fn align(s1: &str, s2: &str, scoring: &Scoring, local: bool) -> Alignment {
+    let a = segment(s1, scoring.segmentation);
+    let b = segment(s2, scoring.segmentation);
+    let (n, m) = (a.len(), b.len());
+
+    let gap = |k: usize| scoring.gap_open + (k.saturating_sub(1)) as f32 * scoring.gap_extend;
+    let origin = |i: usize, j: usize| Cell {
+        score: 0.0,
+        start: (i, j),
+    };
+
+    let mut h_prev: Vec<Cell> = (0..=m)
+        .map(|j| {
+            if local || j == 0 {
+                origin(0, j)
+            } else {
+                Cell {
+                    score: -gap(j),
+                    start: (0, 0),
+                }
+            }
+        })
+        .collect();
+    let mut f_prev: Vec<Cell> = vec![Cell::NONE; m + 1];
+    let mut best = if local { origin(0, 0) } else { h_prev[m] };
+    let mut best_end = (0, if local { 0 } else { m });
+
+    for i in 1..=n {
+        let mut h_cur: Vec<Cell> = Vec::with_capacity(m + 1);
+        let mut f_cur: Vec<Cell> = Vec::with_capacity(m + 1);
+        h_cur.push(if local {
+            origin(i, 0)
+        } else {
+            Cell {
+                score: -gap(i),
+                start: (0, 0),
+            }
+        });
+        f_cur.push(Cell::NONE);
+        let mut e = Cell::NONE;
+
+        for j in 1..=m {
+            e = h_cur[j - 1]
+                .add(-scoring.gap_open)
+                .best(e.add(-scoring.gap_extend));
+            let f = h_prev[j]
+                .add(-scoring.gap_open)
+                .best(f_prev[j].add(-scoring.gap_extend));
+            let mut h = h_prev[j - 1]
+                .add(scoring.sub(a[i - 1], b[j - 1]))
+                .best(e)
+                .best(f);
+            if local {
+                h = h.best(origin(i, j));
+                if h.score > best.score {
+                    best = h;
+                    best_end = (i, j);
+                }
+            }
+            h_cur.push(h);
+            f_cur.push(f);
+        }
+
+        h_prev = h_cur;
+        f_prev = f_cur;
+    }
+
+    if !local {
+        best = h_prev[m];
+        best_end = (n, m);
+    }
+
+    let raw = best.score;
+    let norm = if local {
+        scoring.self_score(&a).min(scoring.self_score(&b))
+    } else {
+        scoring.self_score(&a).max(scoring.self_score(&b))
+    };
+    let score = if n == 0 && m == 0 {
+        1.0
+    } else if norm <= 0.0 {
+        0.0
+    } else {
+        (raw / norm).clamp(0.0, 1.0)
+    };
+
+    Alignment {
+        raw,
+        score,
+        span1: byte_range(s1, best.start.0..best_end.0, scoring.segmentation),
+        span2: byte_range(s2, best.start.1..best_end.1, scoring.segmentation),
+    }
+}
@@ -0,0 +1,83 @@
+fn pattern_masks(pattern: &[u8]) -> [u64; 256] {
+    let mut peq = [0u64; 256];
+    for (i, &c) in pattern.iter().enumerate() {
+        peq[c as usize] |= 1 << i;
+    }
+    peq
+}
+
+/// Levenshtein distance over bytes. Uses Myers' bit-parallel algorithm when
+/// the shorter input fits in a machine word and a single-row DP otherwise.
+pub fn levenshtein_ascii(a: &[u8], b: &[u8]) -> usize {
+    let (pattern, text) = if a.len() <= b.len() { (a, b) } else { (b, a) };
+    let m = pattern.len();
+    if m == 0 {
+        return text.len();
+    }
+    if m > 64 {
+        return levenshtein_row(pattern, text);
+    }
+
+    let peq = pattern_masks(pattern);
+    let last = 1u64 << (m - 1);
+    let mut pv = u64::MAX;
+    let mut mv = 0u64;
+    let mut score = m;
+
+    for &c in text {
+        let eq = peq[c as usize];
+        let xv = eq | mv;
+        let xh = ((eq & pv).wrapping_add(pv) ^ pv) | eq;
+        let mut ph = mv | !(xh | pv);
+        let mut mh = pv & xh;
+        if ph & last != 0 {
+            score += 1;
+        }
+        if mh & last != 0 {
+            score -= 1;
+        }
+        ph = (ph << 1) | 1;
+        mh <<= 1;
+        pv = mh | !(xv | ph);
+        mv = ph & xv;
+    }
+
+    score
+}
+
+fn levenshtein_row(a: &[u8], b: &[u8]) -> usize {
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for &x in a {
+        let mut diagonal = row[0];
+        row[0] += 1;
+        for (j, &y) in b.iter().enumerate() {
+            let substitution = diagonal + usize::from(x != y);
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(diagonal + 1);
+        }
+    }
+    row[b.len()]
+}
+
+/// Length of the longest common subsequence over bytes, bit-parallel
+/// (Hyyrö) when the shorter input fits in a machine word.
+pub fn lcs_ascii(a: &[u8], b: &[u8]) -> usize {
+    let (pattern, text) = if a.len() <= b.len() { (a, b) } else { (b, a) };
+    let m = pattern.len();
+    if m == 0 {
+        return 0;
+    }
+    if m > 64 {
+        return crate::components::generic::lcs(a, b);
+    }
+
+    let peq = pattern_masks(pattern);
+    let mask = if m == 64 { u64::MAX } else { (1u64 << m) - 1 };
+    let mut v = u64::MAX;
+    for &c in text {
+        let u = v & peq[c as usize];
+        v = v.wrapping_add(u) | (v - u);
+    }
+
+    m - (v & mask).count_ones() as usize
+}
@@ -0,0 +1,55 @@
+use crate::Calc;
+use crate::components::func::Func;
+use kenzu::Builder;
+use std::sync::Arc;
+
+pub type Scorer = Func<dyn Fn(&str, &str) -> f32 + Send + Sync>;
+
+impl Scorer {
+    pub fn new<F>(f: F) -> Self
+    where
+        F: Fn(&str, &str) -> f32 + Send + Sync + 'static,
+    {
+        Func(Arc::new(f))
+    }
+}
+
+/// A `Calc` backed by a closure, for one-off metrics that do not deserve
+/// their own struct.
+#[derive(Debug, Builder, Clone)]
+pub struct Closure {
+    #[set(value = 1.0)]
+    pub weight: f32,
+    pub label: String,
+    #[set(value = 1.0)]
+    pub cost: f32,
+    #[set(value = Scorer::new(|_, _| 0.0))]
+    pub scorer: Scorer,
+}
+
+impl Closure {
+    pub fn named<F>(label: &str, f: F) -> Self
+    where
+        F: Fn(&str, &str) -> f32 + Send + Sync + 'static,
+    {
+        Self::new().label(label).scorer(Scorer::new(f))
+    }
+}
+
+impl Calc for Closure {
+    fn calc(&self, s1: String, s2: String) -> f32 {
+        (self.scorer)(&s1, &s2).clamp(0.0, 1.0)
+    }
+
+    fn get_weight(&self) -> f32 {
+        self.weight
+    }
+
+    fn get_label(&self) -> &str {
+        &self.label
+    }
+
+    fn get_cost(&self) -> f32 {
+        self.cost
+    }
+}
@@ -0,0 +1,301 @@
+use super::prelude::*;
+use kenzu::Builder;
+use std::sync::Arc;
+
+pub type Predicate = Func<dyn Fn(&str, &str) -> bool + Send + Sync>;
+
+impl Predicate {
+    pub fn new<F>(f: F) -> Self
+    where
+        F: Fn(&str, &str) -> bool + Send + Sync + 'static,
+    {
+        Func(Arc::new(f))
+    }
+}
+
+fn names(flow: &[Box<dyn Calc>]) -> String {
+    flow.iter()
+        .map(|algo| algo.get_name())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn cost(flow: &[Box<dyn Calc>]) -> f32 {
+    flow.iter().map(|algo| algo.get_cost()).sum()
+}
+
+fn scores<'a>(
+    flow: &'a [Box<dyn Calc>],
+    s1: &'a str,
+    s2: &'a str,
+) -> impl Iterator<Item = f32> + 'a {
+    flow.iter()
+        .map(move |algo| algo.calc(s1.to_string(), s2.to_string()).clamp(0.0, 1.0))
+}
+
+/// Best score among the `flow`.
+#[derive(Debug, Builder)]
+pub struct Max {
+    #[set(value = 1.0)]
+    pub weight: f32,
+    pub label: String,
+    pub flow: Vec<Box<dyn Calc>>,
+}
+
+impl Calc for Max {
+    fn calc(&self, s1: String, s2: String) -> f32 {
+        scores(&self.flow, &s1, &s2).fold(0.0, f32::max)
+    }
+
+    fn get_weight(&self) -> f32 {
+        self.weight
+    }
+
+    fn get_label(&self) -> &str {
+        &self.label
+    }
+
+    fn get_cost(&self) -> f32 {
+        cost(&self.flow)
+    }
+
+    fn get_algo_name(&self) -> String {
+        format!("Max({})", names(&self.flow))
+    }
+}
+
+/// Worst score among the `flow`, 0.0 when it is empty.
+#[derive(Debug, Builder)]
+pub struct Min {
+    #[set(value = 1.0)]
+    pub weight: f32,
+    pub label: String,
+    pub flow: Vec<Box<dyn Calc>>,
+}
+
+impl Calc for Min {
+    fn calc(&self, s1: String, s2: String) -> f32 {
+        if self.flow.is_empty() {
+            return 0.0;
+        }
+        scores(&self.flow, &s1, &s2).fold(1.0, f32::min)
+    }
+
+    fn get_weight(&self) -> f32 {
+        self.weight
+    }
+
+    fn get_label(&self) -> &str {
+        &self.label
+    }
+
+    fn get_cost(&self) -> f32 {
+        cost(&self.flow)
+    }
+
+    fn get_algo_name(&self) -> String {
+        format!("Min({})", names(&self.flow))
+    }
+}
+
+/// Mean of the `flow` weighted by each algorithm's own `get_weight`.
+#[derive(Debug, Builder)]
+pub struct WeightedMean {
+    #[set(value = 1.0)]
+    pub weight: f32,
+    pub label: String,
+    pub flow: Vec<Box<dyn Calc>>,
+}
+
+impl Calc for WeightedMean {
+    fn calc(&self, s1: String, s2: String) -> f32 {
+        let weights: Vec<f32> = self
+            .flow
+            .iter()
+            .map(|algo| algo.get_weight().max(0.0))
+            .collect();
+        let total_weight: f32 = weights.iter().sum();
+        if total_weight == 0.0 {
+            return 0.0;
+        }
+
+        let total: f32 = scores(&self.flow, &s1, &s2)
+            .zip(&weights)
+            .map(|(score, weight)| score * weight)
+            .sum();
+        total / total_weight
+    }
+
+    fn get_weight(&self) -> f32 {
+        self.weight
+    }
+
+    fn get_label(&self) -> &str {
+        &self.label
+    }
+
+    fn get_cost(&self) -> f32 {
+        cost(&self.flow)
+    }
+
+    fn get_algo_name(&self) -> String {
+        format!("WeightedMean({})", names(&self.flow))
+    }
+}
+
+/// Product of the `flow` scores, so any zero vetoes the pair.
+#[derive(Debug, Builder)]
+pub struct Product {
+    #[set(value = 1.0)]
+    pub weight: f32,
+    pub label: String,
+    pub flow: Vec<Box<dyn Calc>>,
+}
+
+impl Calc for Product {
+    fn calc(&self, s1: String, s2: String) -> f32 {
+        if self.flow.is_empty() {
+            return 0.0;
+        }
+        scores(&self.flow, &s1, &s2).product()
+    }
+
+    fn get_weight(&self) -> f32 {
+        self.weight
+    }
+
+    fn get_label(&self) -> &str {
+        &self.label
+    }
+
+    fn get_cost(&self) -> f32 {
+        cost(&self.flow)
+    }
+
+    fn get_algo_name(&self) -> String {
+        format!("Product({})", names(&self.flow))
+    }
+}
+
+/// Passes the `inner` score through when it reaches `threshold`, otherwise 0.0.
+#[derive(Debug, Builder)]
+pub struct Threshold {
+    #[set(value = 1.0)]
+    pub weight: f32,
+    pub label: String,
+    #[set(value = Levenshtein::new().to_box())]
+    pub inner: Box<dyn Calc>,
+    #[set(value = 0.5)]
+    pub threshold: f32,
+}
+
+impl Calc for Threshold {
+    fn calc(&self, s1: String, s2: String) -> f32 {
+        let score = self.inner.calc(s1, s2).clamp(0.0, 1.0);
+        if score >= self.threshold { score } else { 0.0 }
+    }
+
+    fn get_weight(&self) -> f32 {
+        self.weight
+    }
+
+    fn get_label(&self) -> &str {
+        &self.label
+    }
+
+    fn get_cost(&self) -> f32 {
+        self.inner.get_cost()
+    }
+
+    fn get_algo_name(&self) -> String {
+        format!("Threshold({})", self.inner.get_name())
+    }
+}
+
+/// Scores with `then` when `predicate` holds for the pair and with
+/// `otherwise` when it does not.
+#[derive(Debug, Builder)]
+pub struct IfElse {
+    #[set(value = 1.0)]
+    pub weight: f32,
+    pub label: String,
+    #[set(value = Predicate::new(|_, _| true))]
+    pub predicate: Predicate,
+    #[set(value = Levenshtein::new().to_box())]
+    pub then: Box<dyn Calc>,
+    #[set(value = Levenshtein::new().to_box())]
+    pub otherwise: Box<dyn Calc>,
+}
+
+impl Calc for IfElse {
+    fn calc(&self, s1: String, s2: String) -> f32 {
+        let score = if (self.predicate)(&s1, &s2) {
+            self.then.calc(s1, s2)
+        } else {
+            self.otherwise.calc(s1, s2)
+        };
+        score.clamp(0.0, 1.0)
+    }
+
+    fn get_weight(&self) -> f32 {
+        self.weight
+    }
+
+    fn get_label(&self) -> &str {
+        &self.label
+    }
+
+    fn get_cost(&self) -> f32 {
+        self.then.get_cost().max(self.otherwise.get_cost())
+    }
+
+    fn get_algo_name(&self) -> String {
+        format!(
+            "IfElse({}, {})",
+            self.then.get_name(),
+            self.otherwise.get_name()
+        )
+    }
+}
+
+/// Gives `inner` a different weight in a flow without touching its own
+/// configuration. Everything else, name and preprocessing included, is
+/// passed through.
+#[derive(Debug, Builder)]
+pub struct Weighted {
+    #[set(value = 1.0)]
+    pub weight: f32,
+    pub label: String,
+    #[set(value = Levenshtein::new().to_box())]
+    pub inner: Box<dyn Calc>,
+}
+
+impl Calc for Weighted {
+    fn calc(&self, s1: String, s2: String) -> f32 {
+        self.inner.calc(s1, s2)
+    }
+
+    fn get_weight(&self) -> f32 {
+        self.weight
+    }
+
+    fn get_label(&self) -> &str {
+        &self.label
+    }
+
+    fn get_cost(&self) -> f32 {
+        self.inner.get_cost()
+    }
+
+    fn prepare(&self, s1: &str, s2: &str) -> Option<(String, String)> {
+        self.inner.prepare(s1, s2)
+    }
+
+    fn calc_prepared(&self, s1: String, s2: String) -> f32 {
+        self.inner.calc_prepared(s1, s2)
+    }
+
+    fn get_algo_name(&self) -> String {
+        self.inner.get_name()
+    }
+}
@@ -0,0 +1,113 @@
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Rule {
+    pub from: Vec<char>,
+    pub to: Vec<char>,
+    pub cost: f32,
+}
+
+/// Confusion-matrix cost model. Pairs are symmetric and may span several
+/// characters on either side (e.g. `"rn"` read as `"m"`).
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Confusion {
+    single: HashMap<(char, char), f32>,
+    multi: Vec<Rule>,
+}
+
+impl Confusion {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Common OCR misreadings. Letters come in both cases since `NekoSearch`
+    /// lowercases before scoring.
+    pub fn ocr() -> Self {
+        [
+            ("0", "O", 0.2),
+            ("0", "o", 0.2),
+            ("O", "o", 0.3),
+            ("0", "D", 0.4),
+            ("1", "l", 0.2),
+            ("1", "I", 0.2),
+            ("l", "I", 0.2),
+            ("1", "i", 0.4),
+            ("l", "i", 0.4),
+            ("5", "S", 0.2),
+            ("5", "s", 0.3),
+            ("8", "B", 0.2),
+            ("8", "b", 0.3),
+            ("2", "Z", 0.3),
+            ("2", "z", 0.3),
+            ("6", "G", 0.4),
+            ("6", "g", 0.4),
+            ("9", "g", 0.4),
+            ("c", "e", 0.4),
+            ("rn", "m", 0.2),
+            ("cl", "d", 0.3),
+            ("vv", "w", 0.2),
+            ("nn", "m", 0.4),
+            ("li", "h", 0.4),
+        ]
+        .into_iter()
+        .fold(Self::new(), |table, (from, to, cost)| {
+            table.pair(from, to, cost)
+        })
+    }
+
+    pub fn pair(mut self, from: &str, to: &str, cost: f32) -> Self {
+        let from: Vec<char> = from.chars().collect();
+        let to: Vec<char> = to.chars().collect();
+        if from.is_empty() || to.is_empty() {
+            return self;
+        }
+        if from.len() == 1 && to.len() == 1 {
+            self.single.insert((from[0], to[0]), cost);
+            self.single.insert((to[0], from[0]), cost);
+        } else {
+            self.multi.push(Rule {
+                from: from.clone(),
+                to: to.clone(),
+                cost,
+            });
+            self.multi.push(Rule {
+                from: to,
+                to: from,
+                cost,
+            });
+        }
+        self
+    }
+
+    /// Loads a table with one `from to cost` entry per line. Blank lines and
+    /// lines starting with `#` are ignored.
+    pub fn parse(table: &str) -> Result<Self, String> {
+        let mut confusion = Self::new();
+        for (n, line) in table.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            let [from, to, cost] = fields[..] else {
+                return Err(format!("line {}: expected `from to cost`", n + 1));
+            };
+            let cost: f32 = cost
+                .parse()
+                .map_err(|_| format!("line {}: invalid cost `{}`", n + 1, cost))?;
+            confusion = confusion.pair(from, to, cost);
+        }
+        Ok(confusion)
+    }
+
+    pub fn substitution(&self, a: char, b: char) -> f32 {
+        if a == b {
+            return 0.0;
+        }
+        self.single.get(&(a, b)).copied().unwrap_or(1.0)
+    }
+
+    pub fn rules(&self) -> &[Rule] {
+        &self.multi
+    }
+}
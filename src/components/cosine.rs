@@ -1,34 +1,59 @@
 use crate::Calc;
+use crate::components::jaccard::{gram_slices, pad};
+use crate::components::types::{Normalization, Segmentation};
+use crate::components::unicode::normalize_unicode;
 use kenzu::Builder;
+use std::borrow::Cow;
 use std::collections::HashMap;
 
 #[derive(Debug, Builder, Clone)]
 pub struct Cosine {
     #[set(value = 1.0)]
     pub weight: f32,
+    pub label: String,
     #[set(value = 2_usize)]
     pub ngram: usize,
+    #[set(value = false)]
+    pub pad: bool,
+    #[set(value = ' ')]
+    pub marker: char,
+    #[set(value = Segmentation::Graphemes)]
+    pub segmentation: Segmentation,
+    #[set(value = Normalization::Nfc)]
+    pub unicode: Normalization,
 }
 
 impl Calc for Cosine {
     fn calc(&self, s1: String, s2: String) -> f32 {
-        fn ngrams(s: &str, n: usize) -> Vec<String> {
-            let chars: Vec<char> = s.chars().collect();
-            if chars.len() < n {
-                return chars.into_iter().map(|c| c.to_string()).collect();
+        let (s1, s2) = (
+            normalize_unicode(&s1, self.unicode),
+            normalize_unicode(&s2, self.unicode),
+        );
+        fn ngrams(s: &str, n: usize, segmentation: Segmentation) -> Vec<&str> {
+            let grams = gram_slices(s, n, segmentation);
+            if grams.len() == 1 && n > 1 {
+                let units = gram_slices(s, 1, segmentation);
+                if units.len() < n {
+                    return units;
+                }
             }
-            chars
-                .windows(n)
-                .map(|w| w.iter().cloned().collect::<String>())
-                .collect()
+            grams
         }
 
         let n = self.ngram.max(1);
-        let b1 = ngrams(&s1, n);
-        let b2 = ngrams(&s2, n);
+        let (s1, s2) = if self.pad {
+            (
+                Cow::Owned(pad(&s1, n, self.marker)),
+                Cow::Owned(pad(&s2, n, self.marker)),
+            )
+        } else {
+            (s1, s2)
+        };
+        let b1 = ngrams(&s1, n, self.segmentation);
+        let b2 = ngrams(&s2, n, self.segmentation);
 
-        let mut vec1: HashMap<String, usize> = HashMap::new();
-        let mut vec2: HashMap<String, usize> = HashMap::new();
+        let mut vec1: HashMap<&str, usize> = HashMap::new();
+        let mut vec2: HashMap<&str, usize> = HashMap::new();
 
         for bg in b1 {
             *vec1.entry(bg).or_insert(0) += 1;
@@ -53,12 +78,19 @@ impl Calc for Cosine {
             (dot as f64) / (norm1 * norm2)
         };
 
-        let mut result = cosine as f32;
-        result *= self.weight;
+        let result = cosine as f32;
         result.clamp(0.0, 1.0)
     }
 
     fn get_weight(&self) -> f32 {
         self.weight
     }
+
+    fn get_label(&self) -> &str {
+        &self.label
+    }
+
+    fn get_cost(&self) -> f32 {
+        1.0
+    }
 }
@@ -0,0 +1,110 @@
+use crate::components::confusion::{Confusion, Rule};
+use crate::components::keyboard::Keyboard;
+use crate::components::unicode::base;
+
+/// Substitution cost model shared by the edit-distance scorers.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub enum Costs {
+    #[default]
+    Uniform,
+    Keyboard(Keyboard),
+    Confusion(Confusion),
+}
+
+impl From<Keyboard> for Costs {
+    fn from(keyboard: Keyboard) -> Self {
+        Costs::Keyboard(keyboard)
+    }
+}
+
+impl From<Confusion> for Costs {
+    fn from(confusion: Confusion) -> Self {
+        Costs::Confusion(confusion)
+    }
+}
+
+impl Costs {
+    pub fn substitution(&self, a: char, b: char) -> f32 {
+        if a == b {
+            return 0.0;
+        }
+        match self {
+            Costs::Uniform => 1.0,
+            Costs::Keyboard(keyboard) => keyboard.substitution(a, b),
+            Costs::Confusion(confusion) => confusion.substitution(a, b),
+        }
+    }
+
+    /// Substitution cost between two comparison units. Multi-codepoint
+    /// graphemes only match themselves.
+    pub fn unit_substitution(&self, a: &str, b: &str) -> f32 {
+        if a == b {
+            return 0.0;
+        }
+        if a.chars().nth(1).is_some() || b.chars().nth(1).is_some() {
+            return 1.0;
+        }
+        self.substitution(base(a), base(b))
+    }
+
+    pub fn rules(&self) -> &[Rule] {
+        match self {
+            Costs::Confusion(confusion) => confusion.rules(),
+            _ => &[],
+        }
+    }
+}
+
+fn spells(units: &[&str], chars: &[char]) -> bool {
+    units.len() == chars.len()
+        && units.iter().zip(chars).all(|(unit, c)| {
+            let mut it = unit.chars();
+            it.next() == Some(*c) && it.next().is_none()
+        })
+}
+
+pub(crate) fn weighted_distance(
+    a: &[&str],
+    b: &[&str],
+    costs: &Costs,
+    transpositions: bool,
+) -> f32 {
+    let (len1, len2) = (a.len(), b.len());
+    let mut matrix = vec![vec![0.0f32; len2 + 1]; len1 + 1];
+
+    for (i, row) in matrix.iter_mut().enumerate() {
+        row[0] = i as f32;
+    }
+    for (j, cell) in matrix[0].iter_mut().enumerate() {
+        *cell = j as f32;
+    }
+
+    for i in 1..=len1 {
+        for j in 1..=len2 {
+            let cost = costs.unit_substitution(a[i - 1], b[j - 1]);
+            let mut val = (matrix[i - 1][j] + 1.0)
+                .min(matrix[i][j - 1] + 1.0)
+                .min(matrix[i - 1][j - 1] + cost);
+
+            if transpositions && i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                let swap = if a[i - 1] == b[j - 1] { 0.0 } else { 1.0 };
+                val = val.min(matrix[i - 2][j - 2] + swap);
+            }
+
+            for rule in costs.rules() {
+                let (n, m) = (rule.from.len(), rule.to.len());
+                if n <= i
+                    && m <= j
+                    && spells(&a[i - n..i], &rule.from)
+                    && spells(&b[j - m..j], &rule.to)
+                {
+                    val = val.min(matrix[i - n][j - m] + rule.cost);
+                }
+            }
+
+            matrix[i][j] = val;
+        }
+    }
+
+    matrix[len1][len2]
+}
@@ -1,72 +1,65 @@
 use crate::Calc;
+use crate::components::cost::{Costs, weighted_distance};
+use crate::components::generic::damerau_levenshtein_similarity;
+use crate::components::types::{Normalization, Segmentation};
+use crate::components::unicode::{is_byte_units, normalize_unicode, segment};
 use kenzu::Builder;
-use std::cmp::min;
 
 #[derive(Debug, Builder, Clone)]
 pub struct DamerauLevenshtein {
     #[set(value = 1.0)]
     pub weight: f32,
+    pub label: String,
+    #[set(value = Costs::Uniform)]
+    pub costs: Costs,
+    #[set(value = Segmentation::Graphemes)]
+    pub segmentation: Segmentation,
+    #[set(value = Normalization::Nfc)]
+    pub unicode: Normalization,
 }
 
-impl Calc for DamerauLevenshtein {
-    fn calc(&self, s1: String, s2: String) -> f32 {
-        let s1_chars: Vec<char> = s1.chars().collect();
-        let s2_chars: Vec<char> = s2.chars().collect();
-        let len1 = s1_chars.len();
-        let len2 = s2_chars.len();
-
-        if len1 == 0 && len2 == 0 {
-            return 1.0;
+impl DamerauLevenshtein {
+    fn similarity(&self, s1: &str, s2: &str) -> f64 {
+        let uniform = matches!(self.costs, Costs::Uniform);
+        if uniform && is_byte_units(s1, self.segmentation) && is_byte_units(s2, self.segmentation) {
+            return damerau_levenshtein_similarity(s1.as_bytes(), s2.as_bytes());
         }
 
-        let mut matrix = vec![vec![0usize; len2 + 1]; len1 + 1];
-
-        for (i, row) in matrix.iter_mut().enumerate().take(len1 + 1) {
-            row[0] = i;
-        }
+        let s1_units = segment(s1, self.segmentation);
+        let s2_units = segment(s2, self.segmentation);
 
-        for (j, cell) in matrix[0].iter_mut().enumerate().take(len2 + 1) {
-            *cell = j;
+        if uniform {
+            damerau_levenshtein_similarity(&s1_units, &s2_units)
+        } else {
+            let distance = weighted_distance(&s1_units, &s2_units, &self.costs, true) as f64;
+            1.0 - distance / s1_units.len().max(s2_units.len()) as f64
         }
+    }
+}
 
-        for i in 1..=len1 {
-            for j in 1..=len2 {
-                let cost = if s1_chars[i - 1] == s2_chars[j - 1] {
-                    0
-                } else {
-                    1
-                };
-                let mut val = min(
-                    min(matrix[i - 1][j] + 1, matrix[i][j - 1] + 1),
-                    matrix[i - 1][j - 1] + cost,
-                );
-
-                if i > 1
-                    && j > 1
-                    && s1_chars[i - 1] == s2_chars[j - 2]
-                    && s1_chars[i - 2] == s2_chars[j - 1]
-                {
-                    val = min(val, matrix[i - 2][j - 2] + cost);
-                }
-
-                matrix[i][j] = val;
-            }
+impl Calc for DamerauLevenshtein {
+    fn calc(&self, s1: String, s2: String) -> f32 {
+        let (s1, s2) = (
+            normalize_unicode(&s1, self.unicode),
+            normalize_unicode(&s2, self.unicode),
+        );
+        if s1.is_empty() && s2.is_empty() {
+            return 1.0;
         }
 
-        let distance = matrix[len1][len2] as f64;
-        let max_len = len1.max(len2) as f64;
-        let similarity = if max_len == 0.0 {
-            1.0
-        } else {
-            1.0 - (distance / max_len)
-        };
-
-        let mut result = similarity as f32;
-        result *= self.weight;
+        let result = self.similarity(&s1, &s2) as f32;
         result.clamp(0.0, 1.0)
     }
 
     fn get_weight(&self) -> f32 {
         self.weight
     }
+
+    fn get_label(&self) -> &str {
+        &self.label
+    }
+
+    fn get_cost(&self) -> f32 {
+        3.0
+    }
 }
@@ -5,6 +5,24 @@ pub trait Calc: Debug {
     fn get_weight(&self) -> f32 {
         1.0
     }
+    fn get_label(&self) -> &str {
+        ""
+    }
+    /// Rough relative cost of one comparison, used to order the flow in
+    /// cascade mode. Linear scans are 1.0, quadratic tables 2.0 to 4.0.
+    fn get_cost(&self) -> f32 {
+        1.0
+    }
+    /// Strings this algorithm compares when it does its own preprocessing.
+    /// `NekoSearch` passes such algorithms the raw input instead of the
+    /// normalized one.
+    fn prepare(&self, _t1: &str, _t2: &str) -> Option<(String, String)> {
+        None
+    }
+    /// Scores the strings `prepare` returned without preprocessing them again.
+    fn calc_prepared(&self, t1: String, t2: String) -> f32 {
+        self.calc(t1, t2)
+    }
     fn to_box(self) -> Box<dyn Calc>
     where
         Self: Sized + 'static,
@@ -19,4 +37,12 @@ pub trait Calc: Debug {
             .unwrap_or("Unknown")
             .to_string()
     }
+    /// The label when one was set, otherwise the algorithm name. This is what
+    /// `NekoSearch` reports in `Find::algo`.
+    fn get_name(&self) -> String {
+        match self.get_label() {
+            "" => self.get_algo_name(),
+            label => label.to_string(),
+        }
+    }
 }
@@ -0,0 +1,141 @@
+use crate::Calc;
+use crate::components::types::{Normalization, Segmentation};
+use crate::components::unicode::{base, normalize_unicode, segment};
+use kenzu::Builder;
+
+/// Editex (Zobel & Dart) phonetic edit distance. Substitutions inside one of
+/// the letter `groups` cost 1 instead of 2, and the `silent` letters cost 1
+/// when skipped.
+#[derive(Debug, Builder, Clone)]
+pub struct Editex {
+    #[set(value = 1.0)]
+    pub weight: f32,
+    pub label: String,
+    #[set(value = english_groups())]
+    pub groups: Vec<String>,
+    #[set(value = vec!['h', 'w'])]
+    pub silent: Vec<char>,
+    #[set(value = Segmentation::Graphemes)]
+    pub segmentation: Segmentation,
+    #[set(value = Normalization::Nfc)]
+    pub unicode: Normalization,
+}
+
+fn groups(list: &[&str]) -> Vec<String> {
+    list.iter().map(|g| g.to_string()).collect()
+}
+
+pub fn english_groups() -> Vec<String> {
+    groups(&[
+        "aeiouy", "bp", "ckq", "dt", "lr", "mn", "gj", "fpv", "sxz", "csz",
+    ])
+}
+
+pub fn portuguese_groups() -> Vec<String> {
+    groups(&[
+        "aáàâãeéêiíoóôõuúüy",
+        "bpv",
+        "cçkq",
+        "dt",
+        "lr",
+        "mn",
+        "gj",
+        "fv",
+        "sçxz",
+        "csz",
+    ])
+}
+
+impl Editex {
+    pub fn portuguese() -> Self {
+        Self::new().groups(portuguese_groups()).silent(vec!['h'])
+    }
+
+    fn r(&self, a: &str, b: &str) -> usize {
+        let single = |u: &str| u.chars().nth(1).is_none();
+        if a == b {
+            0
+        } else if single(a)
+            && single(b)
+            && self
+                .groups
+                .iter()
+                .any(|g| g.contains(base(a)) && g.contains(base(b)))
+        {
+            1
+        } else {
+            2
+        }
+    }
+
+    fn d(&self, a: &str, b: &str) -> usize {
+        if a != b && a.chars().nth(1).is_none() && self.silent.contains(&base(a)) {
+            1
+        } else {
+            self.r(a, b)
+        }
+    }
+
+    pub fn distance(&self, s1: &str, s2: &str) -> usize {
+        let s1 = normalize_unicode(s1, self.unicode).to_lowercase();
+        let s2 = normalize_unicode(s2, self.unicode).to_lowercase();
+        let a: Vec<&str> = std::iter::once(" ")
+            .chain(segment(&s1, self.segmentation))
+            .collect();
+        let b: Vec<&str> = std::iter::once(" ")
+            .chain(segment(&s2, self.segmentation))
+            .collect();
+        let (n, m) = (a.len() - 1, b.len() - 1);
+
+        let mut matrix = vec![vec![0usize; m + 1]; n + 1];
+        for i in 1..=n {
+            matrix[i][0] = matrix[i - 1][0] + self.d(a[i - 1], a[i]);
+        }
+        for j in 1..=m {
+            matrix[0][j] = matrix[0][j - 1] + self.d(b[j - 1], b[j]);
+        }
+
+        for i in 1..=n {
+            for j in 1..=m {
+                matrix[i][j] = (matrix[i - 1][j] + self.d(a[i - 1], a[i]))
+                    .min(matrix[i][j - 1] + self.d(b[j - 1], b[j]))
+                    .min(matrix[i - 1][j - 1] + self.r(a[i], b[j]));
+            }
+        }
+
+        matrix[n][m]
+    }
+}
+
+impl Calc for Editex {
+    fn calc(&self, s1: String, s2: String) -> f32 {
+        let (s1, s2) = (
+            normalize_unicode(&s1, self.unicode),
+            normalize_unicode(&s2, self.unicode),
+        );
+        let max_len = segment(&s1, self.segmentation)
+            .len()
+            .max(segment(&s2, self.segmentation).len());
+        if max_len == 0 {
+            return 1.0;
+        }
+
+        let distance = self.distance(&s1, &s2) as f64;
+        let similarity = 1.0 - distance / (2 * max_len) as f64;
+
+        let result = similarity as f32;
+        result.clamp(0.0, 1.0)
+    }
+
+    fn get_weight(&self) -> f32 {
+        self.weight
+    }
+
+    fn get_label(&self) -> &str {
+        &self.label
+    }
+
+    fn get_cost(&self) -> f32 {
+        3.0
+    }
+}
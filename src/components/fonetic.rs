@@ -1,22 +1,59 @@
 use crate::Calc;
 use kenzu::Builder;
 use rphonetic::{DoubleMetaphone, Encoder};
+use unicode_normalization::UnicodeNormalization;
 
 #[derive(Debug, Builder, Clone)]
 pub struct Metaphone {
     #[set(value = 1.0)]
     pub weight: f32,
+    pub label: String,
 }
 
 impl Metaphone {
     fn get_metaphone_encoder() -> DoubleMetaphone {
         DoubleMetaphone::default()
     }
+
+    // The encoder slices by byte offset and panics on multibyte input, so
+    // accents are folded away before encoding. `None` when the input has
+    // letters but none of them are Latin, since an empty code would match
+    // any other unencodable string.
+    fn letters(s: &str) -> Option<String> {
+        let folded: String = s
+            .nfd()
+            .flat_map(|c| match c {
+                'ß' => "ss".chars().collect(),
+                'æ' => "ae".chars().collect(),
+                'Æ' => "AE".chars().collect(),
+                'œ' => "oe".chars().collect(),
+                'Œ' => "OE".chars().collect(),
+                'ø' => vec!['o'],
+                'Ø' => vec!['O'],
+                'ł' => vec!['l'],
+                'Ł' => vec!['L'],
+                'đ' | 'ð' => vec!['d'],
+                'Đ' | 'Ð' => vec!['D'],
+                'þ' => "th".chars().collect(),
+                'Þ' => "TH".chars().collect(),
+                c => vec![c],
+            })
+            .filter(|c| c.is_ascii())
+            .collect();
+        let had_letters = s.chars().any(char::is_alphabetic);
+        if had_letters && !folded.chars().any(|c| c.is_ascii_alphabetic()) {
+            return None;
+        }
+        Some(folded)
+    }
 }
 
 impl Calc for Metaphone {
     fn calc(&self, s1: String, s2: String) -> f32 {
         let encoder = Metaphone::get_metaphone_encoder();
+        let (Some(s1), Some(s2)) = (Metaphone::letters(&s1), Metaphone::letters(&s2)) else {
+            return 0.0;
+        };
 
         let code1_primary = encoder.encode(&s1);
         let code1_alternate = encoder.encode_alternate(&s1);
@@ -31,12 +68,18 @@ impl Calc for Metaphone {
                 && !code2_alternate.is_empty()
                 && code1_alternate == code2_alternate);
 
-        let mut result = if are_similar { 1.0 } else { 0.0 };
-        result *= self.weight;
-        result.clamp(0.0, 1.0)
+        if are_similar { 1.0 } else { 0.0 }
     }
 
     fn get_weight(&self) -> f32 {
         self.weight
     }
+
+    fn get_label(&self) -> &str {
+        &self.label
+    }
+
+    fn get_cost(&self) -> f32 {
+        1.0
+    }
 }
@@ -0,0 +1,29 @@
+use std::fmt::{Debug, Formatter, Result as FmtResult};
+use std::ops::Deref;
+use std::sync::Arc;
+
+/// A shared closure that fits in a `Builder` field: it is cheap to clone,
+/// prints a placeholder for `Debug` and is called directly through `Deref`.
+/// The concrete signatures (`Substitution`, `Predicate`, ...) are aliases of
+/// it, each with a `new` taking a plain closure.
+pub struct Func<F: ?Sized>(pub Arc<F>);
+
+impl<F: ?Sized> Clone for Func<F> {
+    fn clone(&self) -> Self {
+        Func(Arc::clone(&self.0))
+    }
+}
+
+impl<F: ?Sized> Debug for Func<F> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str("Func(..)")
+    }
+}
+
+impl<F: ?Sized> Deref for Func<F> {
+    type Target = F;
+
+    fn deref(&self) -> &F {
+        &self.0
+    }
+}
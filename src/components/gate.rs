@@ -0,0 +1,83 @@
+use super::prelude::*;
+use kenzu::Builder;
+use std::sync::Arc;
+
+/// What a gate sees: the normalized pair, the algorithms scored so far and,
+/// for `GateStage::After`, the aggregated score.
+#[derive(Debug)]
+pub struct Evidence<'a> {
+    pub txt: &'a str,
+    pub term: &'a str,
+    pub results: &'a [Find],
+    pub score: Option<f32>,
+}
+
+impl Evidence<'_> {
+    /// Score of the algorithm reported as `algo` (its label or name), if it
+    /// already ran.
+    pub fn score_of(&self, algo: &str) -> Option<f32> {
+        self.results
+            .iter()
+            .find(|find| find.algo == algo)
+            .map(|find| find.score)
+    }
+}
+
+pub type Condition = Func<dyn Fn(&Evidence) -> bool + Send + Sync>;
+
+impl Condition {
+    pub fn new<F>(f: F) -> Self
+    where
+        F: Fn(&Evidence) -> bool + Send + Sync + 'static,
+    {
+        Func(Arc::new(f))
+    }
+}
+
+/// A rule that fixes the final score to `outcome` once `when` holds.
+/// `Before` gates are checked before the flow runs and again after every
+/// algorithm, skipping the rest of the flow when they fire; `After` gates are
+/// checked against the aggregated score.
+#[derive(Debug, Builder, Clone)]
+pub struct Gate {
+    pub name: String,
+    #[set(value = GateStage::Before)]
+    pub stage: GateStage,
+    #[set(value = Condition::new(|_| false))]
+    pub when: Condition,
+    #[set(value = 0.0)]
+    pub outcome: f32,
+    /// The condition only reads `txt` and `term`. A `Before` gate is then
+    /// checked once before the flow runs, and the cascade never counts its
+    /// outcome as reachable since running more algorithms cannot change it.
+    pub input_only: bool,
+}
+
+impl Gate {
+    pub fn before<F>(name: &str, outcome: f32, f: F) -> Self
+    where
+        F: Fn(&Evidence) -> bool + Send + Sync + 'static,
+    {
+        Self::new()
+            .name(name)
+            .outcome(outcome)
+            .when(Condition::new(f))
+    }
+
+    pub fn after<F>(name: &str, outcome: f32, f: F) -> Self
+    where
+        F: Fn(&Evidence) -> bool + Send + Sync + 'static,
+    {
+        Self::before(name, outcome, f).stage(GateStage::After)
+    }
+
+    /// Scores 1.0 without running the flow when both sides are equal after
+    /// normalization.
+    pub fn exact_match() -> Self {
+        Self::before("exact_match", 1.0, |e| e.txt == e.term).input_only(true)
+    }
+
+    pub fn check(&self, evidence: &Evidence) -> bool {
+        (self.when)(evidence)
+    }
+}
@@ -0,0 +1,157 @@
+use std::cmp::{max, min};
+use std::hash::Hash;
+
+pub fn levenshtein<T: Eq + Hash>(a: &[T], b: &[T]) -> usize {
+    let (len1, len2) = (a.len(), b.len());
+    let mut matrix = vec![vec![0usize; len2 + 1]; len1 + 1];
+
+    for (i, row) in matrix.iter_mut().enumerate() {
+        row[0] = i;
+    }
+
+    for (j, cell) in matrix[0].iter_mut().enumerate() {
+        *cell = j;
+    }
+
+    for i in 1..=len1 {
+        for j in 1..=len2 {
+            let cost = if a[i - 1] == b[j - 1] { 0 } else { 1 };
+            matrix[i][j] = min(
+                min(matrix[i - 1][j] + 1, matrix[i][j - 1] + 1),
+                matrix[i - 1][j - 1] + cost,
+            );
+        }
+    }
+
+    matrix[len1][len2]
+}
+
+pub fn damerau_levenshtein<T: Eq + Hash>(a: &[T], b: &[T]) -> usize {
+    let (len1, len2) = (a.len(), b.len());
+    let mut matrix = vec![vec![0usize; len2 + 1]; len1 + 1];
+
+    for (i, row) in matrix.iter_mut().enumerate() {
+        row[0] = i;
+    }
+
+    for (j, cell) in matrix[0].iter_mut().enumerate() {
+        *cell = j;
+    }
+
+    for i in 1..=len1 {
+        for j in 1..=len2 {
+            let cost = if a[i - 1] == b[j - 1] { 0 } else { 1 };
+            let mut val = min(
+                min(matrix[i - 1][j] + 1, matrix[i][j - 1] + 1),
+                matrix[i - 1][j - 1] + cost,
+            );
+
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                val = min(val, matrix[i - 2][j - 2] + cost);
+            }
+
+            matrix[i][j] = val;
+        }
+    }
+
+    matrix[len1][len2]
+}
+
+pub fn lcs<T: Eq + Hash>(a: &[T], b: &[T]) -> usize {
+    let (n, m) = (a.len(), b.len());
+    let mut matrix: Vec<Vec<usize>> = vec![vec![0_usize; m + 1]; n + 1];
+
+    for i in 1..=n {
+        for j in 1..=m {
+            if a[i - 1] == b[j - 1] {
+                matrix[i][j] = matrix[i - 1][j - 1] + 1;
+            } else {
+                matrix[i][j] = max(matrix[i - 1][j], matrix[i][j - 1]);
+            }
+        }
+    }
+
+    matrix[n][m]
+}
+
+pub fn jaro<T: Eq + Hash>(a: &[T], b: &[T]) -> f64 {
+    let (len1, len2) = (a.len(), b.len());
+
+    if len1 == 0 && len2 == 0 {
+        return 1.0;
+    }
+    if len1 == 0 || len2 == 0 {
+        return 0.0;
+    }
+
+    let max_dist = (len1.max(len2) / 2).saturating_sub(1);
+
+    let mut b_flags = vec![false; len2];
+    let mut a_matches: Vec<&T> = Vec::new();
+
+    for (i, x) in a.iter().enumerate() {
+        let start = i.saturating_sub(max_dist);
+        let end = (i + max_dist + 1).min(len2);
+        for j in start..end {
+            if !b_flags[j] && *x == b[j] {
+                a_matches.push(x);
+                b_flags[j] = true;
+                break;
+            }
+        }
+    }
+
+    let matches = a_matches.len();
+    if matches == 0 {
+        return 0.0;
+    }
+
+    let b_matches = b.iter().zip(&b_flags).filter(|(_, f)| **f).map(|(y, _)| y);
+    let transpositions = a_matches
+        .iter()
+        .zip(b_matches)
+        .filter(|(x, y)| **x != *y)
+        .count();
+
+    let m = matches as f64;
+    let t = (transpositions as f64) / 2.0;
+
+    (m / len1 as f64 + m / len2 as f64 + (m - t) / m) / 3.0
+}
+
+pub fn jaro_winkler<T: Eq + Hash>(a: &[T], b: &[T], prefix: usize) -> f64 {
+    let jaro = jaro(a, b);
+    if a.is_empty() || b.is_empty() {
+        return jaro;
+    }
+    let prefix_len = a
+        .iter()
+        .zip(b.iter())
+        .take(prefix)
+        .take_while(|(x, y)| x == y)
+        .count();
+    jaro + (prefix_len as f64) * 0.076 * (1.0 - jaro)
+}
+
+pub fn levenshtein_similarity<T: Eq + Hash>(a: &[T], b: &[T]) -> f64 {
+    let max_len = a.len().max(b.len());
+    if max_len == 0 {
+        return 1.0;
+    }
+    1.0 - levenshtein(a, b) as f64 / max_len as f64
+}
+
+pub fn damerau_levenshtein_similarity<T: Eq + Hash>(a: &[T], b: &[T]) -> f64 {
+    let max_len = a.len().max(b.len());
+    if max_len == 0 {
+        return 1.0;
+    }
+    1.0 - damerau_levenshtein(a, b) as f64 / max_len as f64
+}
+
+pub fn lcs_similarity<T: Eq + Hash>(a: &[T], b: &[T]) -> f64 {
+    if a.is_empty() || b.is_empty() {
+        return 0.0;
+    }
+    lcs(a, b) as f64 / a.len().max(b.len()) as f64
+}
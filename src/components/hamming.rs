@@ -0,0 +1,94 @@
+use crate::Calc;
+use crate::components::levenshtein::Levenshtein;
+use crate::components::types::{LengthMismatch, Normalization, Segmentation};
+use crate::components::unicode::{normalize_unicode, segment};
+use kenzu::Builder;
+
+/// Position-sensitive Hamming similarity for fixed-format codes. `positions`
+/// holds per-position weights (missing entries weigh 1.0), so check digits or
+/// prefixes can count more than the rest of the code.
+#[derive(Debug, Builder, Clone)]
+pub struct Hamming {
+    #[set(value = 1.0)]
+    pub weight: f32,
+    pub label: String,
+    #[set(value = LengthMismatch::Pad)]
+    pub mismatch: LengthMismatch,
+    pub positions: Vec<f32>,
+    #[set(value = Segmentation::Graphemes)]
+    pub segmentation: Segmentation,
+    #[set(value = Normalization::Nfc)]
+    pub unicode: Normalization,
+}
+
+impl Hamming {
+    fn position_weight(&self, i: usize) -> f32 {
+        self.positions.get(i).copied().unwrap_or(1.0).max(0.0)
+    }
+
+    pub fn similarity(&self, s1: &str, s2: &str) -> Result<f32, String> {
+        let (s1, s2) = (
+            normalize_unicode(s1, self.unicode),
+            normalize_unicode(s2, self.unicode),
+        );
+        let a = segment(&s1, self.segmentation);
+        let b = segment(&s2, self.segmentation);
+
+        if a.len() != b.len() {
+            match self.mismatch {
+                LengthMismatch::Error => {
+                    return Err(format!(
+                        "hamming requires equal lengths, got {} and {}",
+                        a.len(),
+                        b.len()
+                    ));
+                }
+                LengthMismatch::Levenshtein => {
+                    return Ok(Levenshtein::new()
+                        .segmentation(self.segmentation)
+                        .calc(s1.to_string(), s2.to_string()));
+                }
+                LengthMismatch::Pad => {}
+            }
+        }
+
+        let len = a.len().max(b.len());
+        if len == 0 {
+            return Ok(1.0);
+        }
+
+        let mut total = 0.0f32;
+        let mut distance = 0.0f32;
+        for i in 0..len {
+            let w = self.position_weight(i);
+            total += w;
+            if a.get(i) != b.get(i) {
+                distance += w;
+            }
+        }
+
+        if total == 0.0 {
+            return Ok(1.0);
+        }
+        Ok(1.0 - distance / total)
+    }
+}
+
+impl Calc for Hamming {
+    fn calc(&self, s1: String, s2: String) -> f32 {
+        let result = self.similarity(&s1, &s2).unwrap_or(0.0);
+        result.clamp(0.0, 1.0)
+    }
+
+    fn get_weight(&self) -> f32 {
+        self.weight
+    }
+
+    fn get_label(&self) -> &str {
+        &self.label
+    }
+
+    fn get_cost(&self) -> f32 {
+        1.0
+    }
+}
@@ -1,35 +1,77 @@
 use crate::Calc;
+use crate::components::types::{Gram, Normalization, Segmentation};
+use crate::components::unicode::{boundaries, is_byte_units, normalize_unicode};
 use kenzu::Builder;
+use std::borrow::Cow;
 use std::collections::HashSet;
 
 #[derive(Debug, Builder, Clone)]
 pub struct Jaccard {
     #[set(value = 1.0)]
     pub weight: f32,
+    pub label: String,
     #[set(value = 2_usize)]
     pub ngram: usize,
+    #[set(value = Gram::Char)]
+    pub gram: Gram,
+    #[set(value = Segmentation::Graphemes)]
+    pub segmentation: Segmentation,
+    #[set(value = Normalization::Nfc)]
+    pub unicode: Normalization,
+    #[set(value = false)]
+    pub pad: bool,
+    #[set(value = ' ')]
+    pub marker: char,
+}
+
+pub(crate) fn pad(s: &str, n: usize, marker: char) -> String {
+    if s.is_empty() {
+        return String::new();
+    }
+    let edge: String = std::iter::repeat_n(marker, n.saturating_sub(1)).collect();
+    format!("{edge}{s}{edge}")
+}
+
+/// Zero-copy n-grams of comparison units, borrowed from `s`.
+pub(crate) fn gram_slices(s: &str, n: usize, segmentation: Segmentation) -> Vec<&str> {
+    if s.is_empty() || n == 0 {
+        return Vec::new();
+    }
+    if is_byte_units(s, segmentation) {
+        let n = n.min(s.len());
+        return (0..=s.len() - n).map(|i| &s[i..i + n]).collect();
+    }
+    let offsets = boundaries(s, segmentation);
+    let units = offsets.len() - 1;
+    let n = n.min(units);
+    (0..=units - n)
+        .map(|i| &s[offsets[i]..offsets[i + n]])
+        .collect()
+}
+
+pub(crate) fn gram_set(s: &str, n: usize, gram: Gram, segmentation: Segmentation) -> HashSet<&str> {
+    match gram {
+        Gram::Char => gram_slices(s, n.max(1), segmentation).into_iter().collect(),
+        Gram::Word => s.split_whitespace().collect(),
+    }
 }
 
 impl Calc for Jaccard {
     fn calc(&self, s1: String, s2: String) -> f32 {
-        fn ngrams(s: &str, n: usize) -> HashSet<String> {
-            let chars: Vec<char> = s.chars().collect();
-            if chars.is_empty() || n == 0 {
-                return HashSet::new();
-            }
-            let n = n.min(chars.len());
-            if n == 1 {
-                return chars.into_iter().map(|c| c.to_string()).collect();
-            }
-            chars
-                .windows(n)
-                .map(|w| w.iter().cloned().collect::<String>())
-                .collect::<HashSet<String>>()
-        }
-
-        let n = self.ngram.max(1);
-        let set1 = ngrams(&s1, n);
-        let set2 = ngrams(&s2, n);
+        let (s1, s2) = (
+            normalize_unicode(&s1, self.unicode),
+            normalize_unicode(&s2, self.unicode),
+        );
+        let (s1, s2) = if self.pad && self.gram == Gram::Char {
+            (
+                Cow::Owned(pad(&s1, self.ngram, self.marker)),
+                Cow::Owned(pad(&s2, self.ngram, self.marker)),
+            )
+        } else {
+            (s1, s2)
+        };
+        let set1 = gram_set(&s1, self.ngram, self.gram, self.segmentation);
+        let set2 = gram_set(&s2, self.ngram, self.gram, self.segmentation);
 
         let intersection = set1.intersection(&set2).count() as f64;
         let union = set1.union(&set2).count() as f64;
@@ -39,12 +81,19 @@ impl Calc for Jaccard {
         } else {
             intersection / union
         };
-        let mut result = similarity as f32;
-        result *= self.weight;
+        let result = similarity as f32;
         result.clamp(0.0, 1.0)
     }
 
     fn get_weight(&self) -> f32 {
         self.weight
     }
+
+    fn get_label(&self) -> &str {
+        &self.label
+    }
+
+    fn get_cost(&self) -> f32 {
+        1.0
+    }
 }
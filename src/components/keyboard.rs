@@ -0,0 +1,147 @@
+use std::collections::HashMap;
+
+/// Physical keyboard model used to make substitutions between neighbouring
+/// keys cheaper. Keys closer than `reach` (in key widths) cost `adjacent`,
+/// every other substitution costs 1.0. Custom layouts are built with
+/// [`Keyboard::from_rows`] or [`Keyboard::from_grid`] and passed by value.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Keyboard {
+    pub name: String,
+    pub keys: HashMap<char, (f32, f32)>,
+    pub adjacent: f32,
+    pub reach: f32,
+}
+
+fn single_keys(row: &str) -> Vec<String> {
+    row.chars().map(|c| c.to_string()).collect()
+}
+
+impl Keyboard {
+    /// Builds a layout from rows of keys. Each row carries its horizontal
+    /// stagger and a list of keys, every key listing the characters it types.
+    pub fn from_grid(name: &str, rows: &[(f32, Vec<String>)]) -> Self {
+        let mut keys = HashMap::new();
+        for (y, (offset, row)) in rows.iter().enumerate() {
+            for (x, key) in row.iter().enumerate() {
+                for c in key.chars().flat_map(char::to_lowercase) {
+                    keys.insert(c, (x as f32 + offset, y as f32));
+                }
+            }
+        }
+        Keyboard {
+            name: name.to_string(),
+            keys,
+            adjacent: 0.5,
+            reach: 1.3,
+        }
+    }
+
+    pub fn from_rows(name: &str, rows: &[(f32, &str)]) -> Self {
+        let rows: Vec<(f32, Vec<String>)> = rows
+            .iter()
+            .map(|(offset, row)| (*offset, single_keys(row)))
+            .collect();
+        Self::from_grid(name, &rows)
+    }
+
+    pub fn qwerty() -> Self {
+        Self::from_rows(
+            "qwerty",
+            &[
+                (0.0, "1234567890-="),
+                (0.5, "qwertyuiop[]"),
+                (0.75, "asdfghjkl;'"),
+                (1.25, "zxcvbnm,./"),
+            ],
+        )
+    }
+
+    pub fn azerty() -> Self {
+        Self::from_rows(
+            "azerty",
+            &[
+                (0.0, "&é\"'(-è_çà)="),
+                (0.5, "azertyuiop^$"),
+                (0.75, "qsdfghjklmù*"),
+                (0.25, "<wxcvbn,;:!"),
+            ],
+        )
+    }
+
+    pub fn abnt2() -> Self {
+        Self::from_rows(
+            "abnt2",
+            &[
+                (0.0, "1234567890-="),
+                (0.5, "qwertyuiop´["),
+                (0.75, "asdfghjklç~]"),
+                (0.25, "\\zxcvbnm,.;/"),
+            ],
+        )
+    }
+
+    pub fn dvorak() -> Self {
+        Self::from_rows(
+            "dvorak",
+            &[
+                (0.0, "1234567890[]"),
+                (0.5, "',.pyfgcrl/="),
+                (0.75, "aoeuidhtns-"),
+                (1.25, ";qjkxbmwvz"),
+            ],
+        )
+    }
+
+    pub fn phone() -> Self {
+        let row = |keys: [&str; 3]| (0.0, keys.iter().map(|k| k.to_string()).collect());
+        Self::from_grid(
+            "phone",
+            &[
+                row(["1", "2abc", "3def"]),
+                row(["4ghi", "5jkl", "6mno"]),
+                row(["7pqrs", "8tuv", "9wxyz"]),
+                row(["*", "0 ", "#"]),
+            ],
+        )
+    }
+
+    /// Cost of substituting neighbouring keys, kept within `0.0..=1.0`.
+    pub fn adjacent(mut self, cost: f32) -> Self {
+        self.adjacent = cost.clamp(0.0, 1.0);
+        self
+    }
+
+    pub fn reach(mut self, reach: f32) -> Self {
+        self.reach = reach.max(0.0);
+        self
+    }
+
+    /// One of the built-in layouts by name.
+    pub fn layout(name: &str) -> Option<Self> {
+        match name.to_lowercase().as_str() {
+            "qwerty" => Some(Self::qwerty()),
+            "azerty" => Some(Self::azerty()),
+            "abnt2" => Some(Self::abnt2()),
+            "dvorak" => Some(Self::dvorak()),
+            "phone" => Some(Self::phone()),
+            _ => None,
+        }
+    }
+
+    pub fn distance(&self, a: char, b: char) -> Option<f32> {
+        let lower = |c: char| c.to_lowercase().next().unwrap_or(c);
+        let (x1, y1) = self.keys.get(&lower(a))?;
+        let (x2, y2) = self.keys.get(&lower(b))?;
+        Some(((x1 - x2).powi(2) + (y1 - y2).powi(2)).sqrt())
+    }
+
+    pub fn substitution(&self, a: char, b: char) -> f32 {
+        if a == b {
+            return 0.0;
+        }
+        match self.distance(a, b) {
+            Some(distance) if distance < self.reach => self.adjacent.clamp(0.0, 1.0),
+            _ => 1.0,
+        }
+    }
+}
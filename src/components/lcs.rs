@@ -1,49 +1,42 @@
 use crate::Calc;
+use crate::components::ascii::lcs_ascii;
+use crate::components::generic::lcs_similarity;
+use crate::components::types::{Normalization, Segmentation};
+use crate::components::unicode::{is_byte_units, normalize_unicode, segment};
 use kenzu::Builder;
-use std::cmp::max;
 
 #[derive(Debug, Builder, Clone)]
 pub struct Lcs {
     #[set(value = 1.0)]
     pub weight: f32,
+    pub label: String,
+    #[set(value = Segmentation::Graphemes)]
+    pub segmentation: Segmentation,
+    #[set(value = Normalization::Nfc)]
+    pub unicode: Normalization,
 }
 
 impl Calc for Lcs {
     fn calc(&self, s1: String, s2: String) -> f32 {
-        let a: Vec<char> = s1.chars().collect();
-        let b: Vec<char> = s2.chars().collect();
-
-        let n = a.len();
-        let m = b.len();
-
-        if n == 0 || m == 0 {
-            return 0.0;
-        }
-
-        let mut matrix: Vec<Vec<usize>> = vec![vec![0_usize; m + 1]; n + 1];
-
-        for i in 1..=n {
-            for j in 1..=m {
-                if a[i - 1] == b[j - 1] {
-                    matrix[i][j] = matrix[i - 1][j - 1] + 1;
+        let (s1, s2) = (
+            normalize_unicode(&s1, self.unicode),
+            normalize_unicode(&s2, self.unicode),
+        );
+        let similarity =
+            if is_byte_units(&s1, self.segmentation) && is_byte_units(&s2, self.segmentation) {
+                let max_len = s1.len().max(s2.len());
+                if s1.is_empty() || s2.is_empty() {
+                    0.0
                 } else {
-                    matrix[i][j] = max(matrix[i - 1][j], matrix[i][j - 1]);
+                    lcs_ascii(s1.as_bytes(), s2.as_bytes()) as f64 / max_len as f64
                 }
-            }
-        }
-
-        let lcs_length = matrix[n][m] as f32;
-
-        let max_len = max(n, m) as f32;
+            } else {
+                let a = segment(&s1, self.segmentation);
+                let b = segment(&s2, self.segmentation);
+                lcs_similarity(&a, &b)
+            };
 
-        let normalized_score = if max_len == 0.0 {
-            0.0
-        } else {
-            lcs_length / max_len
-        };
-
-        let mut result = normalized_score;
-        result *= self.weight;
+        let result = similarity as f32;
         result.clamp(0.0, 1.0)
     }
 
@@ -51,6 +44,14 @@ impl Calc for Lcs {
         self.weight
     }
 
+    fn get_label(&self) -> &str {
+        &self.label
+    }
+
+    fn get_cost(&self) -> f32 {
+        2.0
+    }
+
     fn get_algo_name(&self) -> String {
         let full_name = std::any::type_name::<Self>();
         full_name.split("::").last().unwrap_or("Lcs").to_string()
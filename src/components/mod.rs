@@ -1,11 +1,40 @@
+pub mod aggregate;
+pub mod alignment;
+pub mod ascii;
+pub mod closure;
+pub mod combinator;
+pub mod confusion;
 pub mod cosine;
+pub mod cost;
 pub mod damerau_levenshtein;
 pub mod dto;
+pub mod editex;
 pub mod fonetic;
+pub mod func;
+pub mod gate;
+pub mod generic;
+pub mod hamming;
 pub mod jaccard;
 pub mod jaro;
+pub mod keyboard;
 pub mod lcs;
 pub mod levenshtein;
+pub mod monge_elkan;
+pub mod needleman_wunsch;
+pub mod overlap;
+pub mod partial;
 pub mod prelude;
+pub mod preprocess;
+pub mod ratcliff_obershelp;
 pub mod search;
+pub mod smith_waterman;
+pub mod soft_tfidf;
+pub mod sorensen_dice;
+pub mod token_set;
+pub mod token_sort;
+pub mod train;
+pub mod trigram;
+pub mod tversky;
 pub mod types;
+pub mod unicode;
+pub mod word_levenshtein;
@@ -0,0 +1,69 @@
+use super::prelude::*;
+use kenzu::Builder;
+
+/// Monge-Elkan similarity: every token of the second string (`term` inside
+/// `NekoSearch`) is matched to its best token in the first and the scores are
+/// averaged. `symmetric` averages both directions.
+#[derive(Debug, Builder)]
+pub struct MongeElkan {
+    #[set(value = 1.0)]
+    pub weight: f32,
+    pub label: String,
+    #[set(value = Jaro::new().to_box())]
+    pub inner: Box<dyn Calc>,
+    #[set(value = false)]
+    pub symmetric: bool,
+}
+
+impl MongeElkan {
+    fn directed(&self, from: &[&str], to: &[&str]) -> f32 {
+        let total: f32 = from
+            .iter()
+            .map(|a| {
+                to.iter()
+                    .map(|b| self.inner.calc(a.to_string(), b.to_string()))
+                    .fold(0.0, f32::max)
+            })
+            .sum();
+        total / from.len() as f32
+    }
+}
+
+impl Calc for MongeElkan {
+    fn calc(&self, s1: String, s2: String) -> f32 {
+        let tokens1: Vec<&str> = s1.split_whitespace().collect();
+        let tokens2: Vec<&str> = s2.split_whitespace().collect();
+
+        if tokens1.is_empty() && tokens2.is_empty() {
+            return 1.0;
+        }
+        if tokens1.is_empty() || tokens2.is_empty() {
+            return 0.0;
+        }
+
+        let similarity = if self.symmetric {
+            (self.directed(&tokens2, &tokens1) + self.directed(&tokens1, &tokens2)) / 2.0
+        } else {
+            self.directed(&tokens2, &tokens1)
+        };
+
+        let result = similarity;
+        result.clamp(0.0, 1.0)
+    }
+
+    fn get_weight(&self) -> f32 {
+        self.weight
+    }
+
+    fn get_label(&self) -> &str {
+        &self.label
+    }
+
+    fn get_cost(&self) -> f32 {
+        4.0 * self.inner.get_cost()
+    }
+
+    fn get_algo_name(&self) -> String {
+        format!("MongeElkan({})", self.inner.get_name())
+    }
+}
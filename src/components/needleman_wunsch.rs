@@ -0,0 +1,62 @@
+use crate::Calc;
+use crate::components::alignment::{Alignment, Scoring, Substitution, align};
+use crate::components::types::{Normalization, Segmentation};
+use crate::components::unicode::normalize_unicode;
+use kenzu::Builder;
+
+#[derive(Debug, Builder, Clone)]
+pub struct NeedlemanWunsch {
+    #[set(value = 1.0)]
+    pub weight: f32,
+    pub label: String,
+    #[set(value = 1.0)]
+    pub match_score: f32,
+    #[set(value = (-1.0))]
+    pub mismatch_score: f32,
+    #[set(value = 1.0)]
+    pub gap_open: f32,
+    #[set(value = 0.5)]
+    pub gap_extend: f32,
+    pub substitution: Option<Substitution>,
+    #[set(value = Segmentation::Graphemes)]
+    pub segmentation: Segmentation,
+    #[set(value = Normalization::Nfc)]
+    pub unicode: Normalization,
+}
+
+impl NeedlemanWunsch {
+    pub fn align(&self, s1: &str, s2: &str) -> Alignment {
+        let scoring = Scoring {
+            match_score: self.match_score,
+            mismatch_score: self.mismatch_score,
+            gap_open: self.gap_open,
+            gap_extend: self.gap_extend,
+            substitution: self.substitution.as_ref(),
+            segmentation: self.segmentation,
+        };
+        align(s1, s2, &scoring, false)
+    }
+}
+
+impl Calc for NeedlemanWunsch {
+    fn calc(&self, s1: String, s2: String) -> f32 {
+        let (s1, s2) = (
+            normalize_unicode(&s1, self.unicode),
+            normalize_unicode(&s2, self.unicode),
+        );
+        let result = self.align(&s1, &s2).score;
+        result.clamp(0.0, 1.0)
+    }
+
+    fn get_weight(&self) -> f32 {
+        self.weight
+    }
+
+    fn get_label(&self) -> &str {
+        &self.label
+    }
+
+    fn get_cost(&self) -> f32 {
+        4.0
+    }
+}
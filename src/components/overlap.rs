@@ -0,0 +1,58 @@
+use crate::Calc;
+use crate::components::jaccard::gram_set;
+use crate::components::types::{Gram, Normalization, Segmentation};
+use crate::components::unicode::normalize_unicode;
+use kenzu::Builder;
+
+#[derive(Debug, Builder, Clone)]
+pub struct Overlap {
+    #[set(value = 1.0)]
+    pub weight: f32,
+    pub label: String,
+    #[set(value = 2_usize)]
+    pub ngram: usize,
+    #[set(value = Gram::Char)]
+    pub gram: Gram,
+    #[set(value = Segmentation::Graphemes)]
+    pub segmentation: Segmentation,
+    #[set(value = Normalization::Nfc)]
+    pub unicode: Normalization,
+}
+
+impl Calc for Overlap {
+    fn calc(&self, s1: String, s2: String) -> f32 {
+        let (s1, s2) = (
+            normalize_unicode(&s1, self.unicode),
+            normalize_unicode(&s2, self.unicode),
+        );
+        let set1 = gram_set(&s1, self.ngram, self.gram, self.segmentation);
+        let set2 = gram_set(&s2, self.ngram, self.gram, self.segmentation);
+
+        if set1.is_empty() && set2.is_empty() {
+            return 1.0;
+        }
+
+        let intersection = set1.intersection(&set2).count() as f64;
+        let smallest = set1.len().min(set2.len()) as f64;
+
+        let similarity = if smallest == 0.0 {
+            0.0
+        } else {
+            intersection / smallest
+        };
+        let result = similarity as f32;
+        result.clamp(0.0, 1.0)
+    }
+
+    fn get_weight(&self) -> f32 {
+        self.weight
+    }
+
+    fn get_label(&self) -> &str {
+        &self.label
+    }
+
+    fn get_cost(&self) -> f32 {
+        1.0
+    }
+}
@@ -0,0 +1,106 @@
+use super::prelude::*;
+use crate::components::unicode::{boundaries, normalize_unicode, segment};
+use kenzu::Builder;
+use std::ops::Range;
+
+#[derive(Debug, Builder)]
+pub struct Partial {
+    #[set(value = 1.0)]
+    pub weight: f32,
+    pub label: String,
+    #[set(value = Levenshtein::new().to_box())]
+    pub inner: Box<dyn Calc>,
+    #[set(value = Segmentation::Graphemes)]
+    pub segmentation: Segmentation,
+    #[set(value = Normalization::Nfc)]
+    pub unicode: Normalization,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PartialMatch {
+    pub score: f32,
+    /// Byte range of the best window inside the longer of the two strings.
+    pub span: Range<usize>,
+    /// Which argument `span` indexes.
+    pub side: Side,
+    pub window: String,
+}
+
+impl Partial {
+    pub fn best(&self, s1: &str, s2: &str) -> PartialMatch {
+        let units1 = segment(s1, self.segmentation).len();
+        let units2 = segment(s2, self.segmentation).len();
+        let (short, long, width, side) = if units1 <= units2 {
+            (s1, s2, units1, Side::Second)
+        } else {
+            (s2, s1, units2, Side::First)
+        };
+        let score = |window: &str| match side {
+            Side::First => self.inner.calc(window.to_string(), short.to_string()),
+            Side::Second => self.inner.calc(short.to_string(), window.to_string()),
+        };
+
+        if short.is_empty() {
+            return PartialMatch {
+                score: self.inner.calc(s1.to_string(), s2.to_string()),
+                span: 0..0,
+                side,
+                window: String::new(),
+            };
+        }
+
+        let offsets = boundaries(long, self.segmentation);
+        let slots = offsets.len() - width;
+
+        let mut best = PartialMatch {
+            score: -1.0,
+            span: 0..0,
+            side,
+            window: String::new(),
+        };
+        for start in 0..slots {
+            let span = offsets[start]..offsets[start + width];
+            let window = &long[span.clone()];
+            let score = score(window);
+            if score > best.score {
+                best = PartialMatch {
+                    score,
+                    span,
+                    side,
+                    window: window.to_string(),
+                };
+                if score >= 1.0 {
+                    break;
+                }
+            }
+        }
+        best
+    }
+}
+
+impl Calc for Partial {
+    fn calc(&self, s1: String, s2: String) -> f32 {
+        let (s1, s2) = (
+            normalize_unicode(&s1, self.unicode),
+            normalize_unicode(&s2, self.unicode),
+        );
+        let result = self.best(&s1, &s2).score;
+        result.clamp(0.0, 1.0)
+    }
+
+    fn get_weight(&self) -> f32 {
+        self.weight
+    }
+
+    fn get_label(&self) -> &str {
+        &self.label
+    }
+
+    fn get_cost(&self) -> f32 {
+        4.0 * self.inner.get_cost()
+    }
+
+    fn get_algo_name(&self) -> String {
+        format!("Partial({})", self.inner.get_name())
+    }
+}
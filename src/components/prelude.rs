@@ -1,10 +1,36 @@
+pub use super::aggregate::*;
+pub use super::alignment::*;
+pub use super::closure::*;
+pub use super::combinator::*;
+pub use super::confusion::*;
 pub use super::cosine::*;
+pub use super::cost::*;
 pub use super::damerau_levenshtein::*;
 pub use super::dto::*;
+pub use super::editex::*;
 pub use super::fonetic::*;
+pub use super::func::*;
+pub use super::gate::*;
+pub use super::hamming::*;
 pub use super::jaccard::*;
 pub use super::jaro::*;
+pub use super::keyboard::*;
 pub use super::lcs::*;
 pub use super::levenshtein::*;
+pub use super::monge_elkan::*;
+pub use super::needleman_wunsch::*;
+pub use super::overlap::*;
+pub use super::partial::*;
+pub use super::preprocess::*;
+pub use super::ratcliff_obershelp::*;
 pub use super::search::*;
-//pub use super::types::*;
+pub use super::smith_waterman::*;
+pub use super::soft_tfidf::*;
+pub use super::sorensen_dice::*;
+pub use super::token_set::*;
+pub use super::token_sort::*;
+pub use super::train::*;
+pub use super::trigram::*;
+pub use super::tversky::*;
+pub use super::types::*;
+pub use super::word_levenshtein::*;
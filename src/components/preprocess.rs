@@ -0,0 +1,158 @@
+use super::prelude::*;
+use crate::components::unicode::{base, normalize_unicode};
+use kenzu::Builder;
+use std::sync::Arc;
+use unicode_segmentation::UnicodeSegmentation;
+
+pub type Transform = Func<dyn Fn(&str) -> String + Send + Sync>;
+
+impl Transform {
+    pub fn new<F>(f: F) -> Self
+    where
+        F: Fn(&str) -> String + Send + Sync + 'static,
+    {
+        Func(Arc::new(f))
+    }
+}
+
+pub type Split = Func<dyn Fn(&str) -> Vec<String> + Send + Sync>;
+
+impl Split {
+    pub fn new<F>(f: F) -> Self
+    where
+        F: Fn(&str) -> Vec<String> + Send + Sync + 'static,
+    {
+        Func(Arc::new(f))
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub enum Normalizer {
+    /// The `NekoSearch` normalizer: lowercase, punctuation turned into spaces.
+    #[default]
+    Neko,
+    /// Original casing, letters and whitespace only.
+    Letters,
+    /// Leaves the text untouched, whitespace included, unless stop words or a
+    /// custom tokenizer need it split.
+    Raw,
+    Custom(Transform),
+}
+
+#[derive(Debug, Clone, Default)]
+pub enum Tokenizer {
+    #[default]
+    Whitespace,
+    Custom(Split),
+}
+
+fn clean(s: &str, unicode: Normalization, keep: impl Fn(char) -> bool) -> String {
+    normalize_unicode(s, unicode)
+        .graphemes(true)
+        .map(|g| if keep(base(g)) { g } else { " " })
+        .collect::<String>()
+        .split_whitespace()
+        .collect::<Vec<&str>>()
+        .join(" ")
+}
+
+pub fn normalize(s: &str, unicode: Normalization) -> String {
+    clean(&s.to_lowercase(), unicode, |c| {
+        c.is_alphanumeric() || c.is_whitespace()
+    })
+}
+
+/// Turns a raw string into what an algorithm compares: select a field, run
+/// the normalizer, split into tokens, drop stop words and join with spaces.
+#[derive(Debug, Builder, Clone)]
+pub struct Preprocess {
+    /// Splits the input into fields and keeps the `field`-th one.
+    pub separator: Option<char>,
+    #[set(value = 0_usize)]
+    pub field: usize,
+    #[set(value = Normalizer::Neko)]
+    pub normalizer: Normalizer,
+    #[set(value = Normalization::Nfc)]
+    pub unicode: Normalization,
+    #[set(value = Tokenizer::Whitespace)]
+    pub tokenizer: Tokenizer,
+    pub stop_words: Vec<String>,
+}
+
+impl Preprocess {
+    pub fn apply(&self, s: &str) -> String {
+        let selected = match self.separator {
+            Some(separator) => s.split(separator).nth(self.field).unwrap_or("").trim(),
+            None => s,
+        };
+
+        let normalized = match &self.normalizer {
+            Normalizer::Neko => normalize(selected, self.unicode),
+            Normalizer::Letters => clean(selected, self.unicode, |c| {
+                c.is_alphabetic() || c.is_whitespace()
+            }),
+            Normalizer::Raw => selected.to_string(),
+            Normalizer::Custom(f) => f(selected),
+        };
+
+        let tokens: Vec<String> = match &self.tokenizer {
+            Tokenizer::Whitespace if self.stop_words.is_empty() => return normalized,
+            Tokenizer::Whitespace => normalized.split_whitespace().map(String::from).collect(),
+            Tokenizer::Custom(f) => f(&normalized),
+        };
+        if tokens.is_empty() {
+            return normalized;
+        }
+
+        let stop_words: Vec<String> = self.stop_words.iter().map(|w| w.to_lowercase()).collect();
+        tokens
+            .into_iter()
+            .filter(|token| !stop_words.contains(&token.to_lowercase()))
+            .collect::<Vec<String>>()
+            .join(" ")
+    }
+}
+
+/// Runs `preprocess` on both strings before handing them to `inner`. Inside
+/// `NekoSearch` it receives the raw `txt` and `term` instead of the globally
+/// normalized ones.
+#[derive(Debug, Builder)]
+pub struct Prepared {
+    #[set(value = 1.0)]
+    pub weight: f32,
+    pub label: String,
+    #[set(value = Levenshtein::new().to_box())]
+    pub inner: Box<dyn Calc>,
+    #[set(value = Preprocess::new())]
+    pub preprocess: Preprocess,
+}
+
+impl Calc for Prepared {
+    fn calc(&self, s1: String, s2: String) -> f32 {
+        self.calc_prepared(self.preprocess.apply(&s1), self.preprocess.apply(&s2))
+    }
+
+    fn get_weight(&self) -> f32 {
+        self.weight
+    }
+
+    fn get_label(&self) -> &str {
+        &self.label
+    }
+
+    fn get_cost(&self) -> f32 {
+        self.inner.get_cost()
+    }
+
+    fn prepare(&self, s1: &str, s2: &str) -> Option<(String, String)> {
+        Some((self.preprocess.apply(s1), self.preprocess.apply(s2)))
+    }
+
+    fn calc_prepared(&self, s1: String, s2: String) -> f32 {
+        self.inner.calc(s1, s2).clamp(0.0, 1.0)
+    }
+
+    fn get_algo_name(&self) -> String {
+        self.inner.get_name()
+    }
+}
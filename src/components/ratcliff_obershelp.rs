@@ -0,0 +1,211 @@
+use crate::Calc;
+use crate::components::func::Func;
+use crate::components::types::{Normalization, Segmentation};
+use crate::components::unicode::{base, normalize_unicode, segment};
+use kenzu::Builder;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+pub type Junk = Func<dyn Fn(char) -> bool + Send + Sync>;
+
+impl Junk {
+    pub fn new<F>(f: F) -> Self
+    where
+        F: Fn(char) -> bool + Send + Sync + 'static,
+    {
+        Func(Arc::new(f))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct MatchingBlock {
+    pub a: usize,
+    pub b: usize,
+    pub size: usize,
+}
+
+/// Ratcliff/Obershelp gestalt pattern matching, reproducing Python's
+/// `difflib.SequenceMatcher`, including its `isjunk` and `autojunk` options.
+/// Compares chars without normalization by default, as difflib does; block
+/// offsets count comparison units.
+#[derive(Debug, Builder, Clone)]
+pub struct RatcliffObershelp {
+    #[set(value = 1.0)]
+    pub weight: f32,
+    pub label: String,
+    #[set(value = true)]
+    pub autojunk: bool,
+    pub junk: Option<Junk>,
+    #[set(value = Segmentation::Chars)]
+    pub segmentation: Segmentation,
+    #[set(value = Normalization::None)]
+    pub unicode: Normalization,
+}
+
+struct Matcher<'a> {
+    a: &'a [&'a str],
+    b: &'a [&'a str],
+    b2j: HashMap<&'a str, Vec<usize>>,
+    bjunk: HashSet<&'a str>,
+}
+
+impl Matcher<'_> {
+    fn find_longest_match(&self, alo: usize, ahi: usize, blo: usize, bhi: usize) -> MatchingBlock {
+        let (a, b) = (self.a, self.b);
+        let is_bjunk = |u: &str| self.bjunk.contains(u);
+        let (mut besti, mut bestj, mut bestsize) = (alo, blo, 0);
+        let mut j2len: HashMap<usize, usize> = HashMap::new();
+
+        for (i, c) in a.iter().enumerate().take(ahi).skip(alo) {
+            let mut newj2len: HashMap<usize, usize> = HashMap::new();
+            if let Some(indices) = self.b2j.get(c) {
+                for &j in indices {
+                    if j < blo {
+                        continue;
+                    }
+                    if j >= bhi {
+                        break;
+                    }
+                    let k = if j > 0 {
+                        j2len.get(&(j - 1)).copied().unwrap_or(0)
+                    } else {
+                        0
+                    } + 1;
+                    newj2len.insert(j, k);
+                    if k > bestsize {
+                        besti = i + 1 - k;
+                        bestj = j + 1 - k;
+                        bestsize = k;
+                    }
+                }
+            }
+            j2len = newj2len;
+        }
+
+        while besti > alo && bestj > blo && !is_bjunk(b[bestj - 1]) && a[besti - 1] == b[bestj - 1]
+        {
+            besti -= 1;
+            bestj -= 1;
+            bestsize += 1;
+        }
+        while besti + bestsize < ahi
+            && bestj + bestsize < bhi
+            && !is_bjunk(b[bestj + bestsize])
+            && a[besti + bestsize] == b[bestj + bestsize]
+        {
+            bestsize += 1;
+        }
+        while besti > alo && bestj > blo && is_bjunk(b[bestj - 1]) && a[besti - 1] == b[bestj - 1] {
+            besti -= 1;
+            bestj -= 1;
+            bestsize += 1;
+        }
+        while besti + bestsize < ahi
+            && bestj + bestsize < bhi
+            && is_bjunk(b[bestj + bestsize])
+            && a[besti + bestsize] == b[bestj + bestsize]
+        {
+            bestsize += 1;
+        }
+
+        MatchingBlock {
+            a: besti,
+            b: bestj,
+            size: bestsize,
+        }
+    }
+}
+
+impl RatcliffObershelp {
+    pub fn matching_blocks(&self, s1: &str, s2: &str) -> Vec<MatchingBlock> {
+        let a = segment(s1, self.segmentation);
+        let b = segment(s2, self.segmentation);
+
+        let mut b2j: HashMap<&str, Vec<usize>> = HashMap::new();
+        for (j, &u) in b.iter().enumerate() {
+            b2j.entry(u).or_default().push(j);
+        }
+
+        let mut bjunk = HashSet::new();
+        if let Some(junk) = &self.junk {
+            bjunk.extend(b2j.keys().copied().filter(|&u| junk(base(u))));
+            b2j.retain(|c, _| !bjunk.contains(c));
+        }
+
+        if self.autojunk && b.len() >= 200 {
+            let ntest = b.len() / 100 + 1;
+            b2j.retain(|_, indices| indices.len() <= ntest);
+        }
+
+        let matcher = Matcher {
+            a: &a,
+            b: &b,
+            b2j,
+            bjunk,
+        };
+
+        let mut queue = vec![(0, a.len(), 0, b.len())];
+        let mut blocks = Vec::new();
+        while let Some((alo, ahi, blo, bhi)) = queue.pop() {
+            let block = matcher.find_longest_match(alo, ahi, blo, bhi);
+            if block.size > 0 {
+                blocks.push(block);
+                if alo < block.a && blo < block.b {
+                    queue.push((alo, block.a, blo, block.b));
+                }
+                if block.a + block.size < ahi && block.b + block.size < bhi {
+                    queue.push((block.a + block.size, ahi, block.b + block.size, bhi));
+                }
+            }
+        }
+        blocks.sort();
+
+        let mut collapsed: Vec<MatchingBlock> = Vec::new();
+        for block in blocks {
+            match collapsed.last_mut() {
+                Some(last) if last.a + last.size == block.a && last.b + last.size == block.b => {
+                    last.size += block.size;
+                }
+                _ => collapsed.push(block),
+            }
+        }
+        collapsed.push(MatchingBlock {
+            a: a.len(),
+            b: b.len(),
+            size: 0,
+        });
+        collapsed
+    }
+
+    pub fn ratio(&self, s1: &str, s2: &str) -> f32 {
+        let (s1, s2) = (
+            normalize_unicode(s1, self.unicode),
+            normalize_unicode(s2, self.unicode),
+        );
+        let length = segment(&s1, self.segmentation).len() + segment(&s2, self.segmentation).len();
+        if length == 0 {
+            return 1.0;
+        }
+        let matches: usize = self.matching_blocks(&s1, &s2).iter().map(|b| b.size).sum();
+        (2.0 * matches as f64 / length as f64) as f32
+    }
+}
+
+impl Calc for RatcliffObershelp {
+    fn calc(&self, s1: String, s2: String) -> f32 {
+        let result = self.ratio(&s1, &s2);
+        result.clamp(0.0, 1.0)
+    }
+
+    fn get_weight(&self) -> f32 {
+        self.weight
+    }
+
+    fn get_label(&self) -> &str {
+        &self.label
+    }
+
+    fn get_cost(&self) -> f32 {
+        4.0
+    }
+}
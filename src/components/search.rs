@@ -1,8 +1,11 @@
 use super::prelude::*;
 use kenzu::Builder;
 
-#[derive(Builder)]
+#[derive(Builder, Debug)]
 pub struct NekoSearch {
+    #[set(value = 1.0)]
+    pub weight: f32,
+    pub label: String,
     pub txt: String,
     pub term: String,
     #[set(value=vec![
@@ -16,64 +19,229 @@ pub struct NekoSearch {
     ])]
     pub flow: Vec<Box<dyn Calc>>,
     pub results: Vec<Find>,
+    #[set(value = Normalization::Nfc)]
+    pub unicode: Normalization,
+    #[set(value = Aggregation::WeightedMean)]
+    pub aggregation: Aggregation,
+    pub gates: Vec<Gate>,
+    /// Name of the gate that decided the last score, if any.
+    pub decision: Option<String>,
+    /// Threshold for cascade evaluation: the flow runs from the cheapest to
+    /// the most expensive algorithm and stops once the best reachable score,
+    /// with every remaining algorithm at 1.0, falls below it. The score is
+    /// then aggregated over the algorithms that ran.
+    pub cascade: Option<f32>,
+    /// Algorithms of the flow that did not run for the last score.
+    pub skipped: Vec<String>,
+    /// Best score the full flow could have reached when the cascade stopped
+    /// early.
+    pub bound: Option<f32>,
+}
+
+pub(crate) struct Run {
+    score: f32,
+    pub(crate) results: Vec<Find>,
+    decision: Option<String>,
+    skipped: Vec<String>,
+    bound: Option<f32>,
 }
 
 #[derive(Builder, Debug)]
 pub struct Find {
     pub algo: String,
+    /// `txt` and `term` as the algorithm actually compared them.
+    pub txt: String,
     pub term: String,
+    /// Raw similarity returned by the algorithm.
     pub score: f32,
-}
-
-fn normalize(s: &str) -> String {
-    s.to_lowercase()
-        .chars()
-        .map(|c| {
-            if c.is_alphanumeric() || c.is_whitespace() {
-                c
-            } else {
-                ' '
-            }
-        })
-        .collect::<String>()
-        .split_whitespace()
-        .collect::<Vec<&str>>()
-        .join(" ")
+    pub weight: f32,
+    /// Share of the weighted mean owed to this algorithm, `score * weight / total_weight`.
+    /// `None` unless the score is the `WeightedMean` of the results, i.e. with
+    /// another aggregation or when a gate decided it.
+    pub contribution: Option<f32>,
 }
 
 impl NekoSearch {
-    pub fn calc(&mut self) -> f32 {
-        let txt = normalize(&self.txt);
-        let term = normalize(&self.term);
-
-        self.results.clear();
+    fn gate(
+        &self,
+        stage: GateStage,
+        txt: &str,
+        term: &str,
+        results: &[Find],
+        score: Option<f32>,
+    ) -> Option<&Gate> {
+        let evidence = Evidence {
+            txt,
+            term,
+            results,
+            score,
+        };
+        // Input-only `Before` gates already had their say before the flow ran.
+        let rerun = stage == GateStage::Before && !results.is_empty();
+        self.gates
+            .iter()
+            .filter(|gate| gate.stage == stage && !(rerun && gate.input_only))
+            .find(|gate| gate.check(&evidence))
+    }
 
-        let mut total_score = 0.0f32;
-        let mut total_weight = 0.0f32;
+    pub(crate) fn score(&self, raw_txt: &str, raw_term: &str) -> Run {
+        let txt = normalize(raw_txt, self.unicode);
+        let term = normalize(raw_term, self.unicode);
 
-        for algo in &self.flow {
-            let score = algo.calc(txt.clone(), term.clone()).clamp(0.0, 1.0);
-            let weight = algo.get_weight().clamp(0.0, 1.0);
+        let mut order: Vec<&dyn Calc> = self.flow.iter().map(|algo| algo.as_ref()).collect();
+        if self.cascade.is_some() {
+            order.sort_by(|a, b| a.get_cost().total_cmp(&b.get_cost()));
+        }
 
-            total_score += score * weight;
-            total_weight += weight;
+        let mut results: Vec<Find> = Vec::with_capacity(order.len());
+        let mut decided = self.gate(GateStage::Before, &txt, &term, &results, None);
+        let mut bound = None;
 
-            self.results.push(
+        for (i, algo) in order.iter().enumerate() {
+            if decided.is_some() || bound.is_some() {
+                break;
+            }
+            let (score, compared) = match algo.prepare(raw_txt, raw_term) {
+                Some((t1, t2)) => (algo.calc_prepared(t1.clone(), t2.clone()), (t1, t2)),
+                None => (
+                    algo.calc(txt.clone(), term.clone()),
+                    (txt.clone(), term.clone()),
+                ),
+            };
+            results.push(
                 Find::new()
-                    .algo(algo.get_algo_name())
-                    .term(term.clone())
-                    .score(score),
+                    .algo(algo.get_name())
+                    .txt(compared.0)
+                    .term(compared.1)
+                    .score(score.clamp(0.0, 1.0))
+                    .weight(algo.get_weight().max(0.0)),
             );
+            decided = self.gate(GateStage::Before, &txt, &term, &results, None);
+            if let Some(threshold) = self.cascade.filter(|_| i + 1 < order.len()) {
+                bound = self
+                    .upper_bound(&results, &order[i + 1..])
+                    .filter(|&best| best < threshold);
+            }
         }
+        let skipped = order[results.len()..]
+            .iter()
+            .map(|algo| algo.get_name())
+            .collect();
+
+        let (score, decided) = match decided {
+            Some(gate) => (gate.outcome, Some(gate)),
+            None => {
+                let score = self.aggregation.apply(&results);
+                match self.gate(GateStage::After, &txt, &term, &results, Some(score)) {
+                    Some(gate) => (gate.outcome, Some(gate)),
+                    None => (score, None),
+                }
+            }
+        };
 
-        if total_weight == 0.0 {
-            return 0.0;
+        let total_weight: f32 = results.iter().map(|find| find.weight).sum();
+        let weighted_mean = matches!(self.aggregation, Aggregation::WeightedMean);
+        if decided.is_none() && weighted_mean && total_weight > 0.0 {
+            for find in &mut results {
+                find.contribution = Some(find.score * find.weight / total_weight);
+            }
         }
 
-        (total_score / total_weight).clamp(0.0, 1.0)
+        Run {
+            score: score.clamp(0.0, 1.0),
+            decision: decided.map(|gate| gate.name.clone()),
+            results,
+            skipped,
+            bound,
+        }
+    }
+
+    /// Best score still reachable when every algorithm in `remaining` scores
+    /// 1.0, or `None` when the aggregation gives no such guarantee. A gate that
+    /// reads the results may still fire, so its outcome is reachable too.
+    fn upper_bound(&self, results: &[Find], remaining: &[&dyn Calc]) -> Option<f32> {
+        if !self.aggregation.is_monotone() {
+            return None;
+        }
+        let mut optimistic: Vec<Find> = results
+            .iter()
+            .map(|find| Find::new().score(find.score).weight(find.weight))
+            .collect();
+        optimistic.extend(
+            remaining
+                .iter()
+                .map(|algo| Find::new().score(1.0).weight(algo.get_weight().max(0.0))),
+        );
+        let best = self.aggregation.apply(&optimistic);
+        Some(
+            self.gates
+                .iter()
+                .filter(|gate| !gate.input_only)
+                .map(|gate| gate.outcome)
+                .fold(best, f32::max),
+        )
+    }
+
+    pub fn calc(&mut self) -> f32 {
+        let run = self.score(&self.txt, &self.term);
+        self.results = run.results;
+        self.decision = run.decision;
+        self.skipped = run.skipped;
+        self.bound = run.bound;
+        run.score
     }
 
     pub fn find(&mut self) -> f32 {
         self.calc()
     }
+
+    /// Scores `txt` against `term` with this configuration, leaving the stored
+    /// input and the last results untouched.
+    pub fn compare(&self, txt: &str, term: &str) -> f32 {
+        self.score(txt, term).score
+    }
+
+    pub fn to_box(self) -> Box<dyn Calc> {
+        self.into()
+    }
+}
+
+/// Runs a whole `NekoSearch` flow as a single `Calc`, so pipelines can be
+/// nested inside another flow or a combinator. It receives the raw input and
+/// applies its own normalization; `txt`, `term` and `results` of the wrapped
+/// search are left untouched.
+#[derive(Debug)]
+pub struct SubFlow(pub NekoSearch);
+
+impl Calc for SubFlow {
+    fn calc(&self, s1: String, s2: String) -> f32 {
+        self.0.compare(&s1, &s2)
+    }
+
+    fn get_weight(&self) -> f32 {
+        self.0.weight
+    }
+
+    fn get_label(&self) -> &str {
+        &self.0.label
+    }
+
+    fn get_cost(&self) -> f32 {
+        self.0.flow.iter().map(|algo| algo.get_cost()).sum()
+    }
+
+    fn prepare(&self, s1: &str, s2: &str) -> Option<(String, String)> {
+        Some((s1.to_string(), s2.to_string()))
+    }
+
+    fn get_algo_name(&self) -> String {
+        let names: Vec<String> = self.0.flow.iter().map(|algo| algo.get_name()).collect();
+        format!("NekoSearch({})", names.join(", "))
+    }
+}
+
+impl From<NekoSearch> for Box<dyn Calc> {
+    fn from(neko: NekoSearch) -> Self {
+        SubFlow(neko).to_box()
+    }
 }
@@ -0,0 +1,99 @@
+use super::prelude::*;
+use kenzu::Builder;
+use std::collections::HashMap;
+
+/// Soft TF-IDF (Cohen, Ravikumar & Fienberg). Tokens are weighted by TF-IDF
+/// and two tokens count as the same term when their `Jaro` similarity is
+/// above `threshold`. IDF weights come from [`SoftTfIdf::fit`]; without
+/// fitting every token has the same IDF and the score degrades to soft TF.
+#[derive(Debug, Builder, Clone)]
+pub struct SoftTfIdf {
+    #[set(value = 1.0)]
+    pub weight: f32,
+    pub label: String,
+    #[set(value = 0.9)]
+    pub threshold: f32,
+    #[set(value = Jaro::new())]
+    pub jaro: Jaro,
+    pub docs: usize,
+    pub df: HashMap<String, usize>,
+}
+
+fn tokens(s: &str) -> Vec<String> {
+    s.split_whitespace().map(|t| t.to_lowercase()).collect()
+}
+
+impl SoftTfIdf {
+    pub fn fit<S: AsRef<str>>(mut self, corpus: &[S]) -> Self {
+        self.docs = corpus.len();
+        self.df.clear();
+        for doc in corpus {
+            let mut seen = tokens(doc.as_ref());
+            seen.sort_unstable();
+            seen.dedup();
+            for token in seen {
+                *self.df.entry(token).or_insert(0) += 1;
+            }
+        }
+        self
+    }
+
+    pub fn idf(&self, token: &str) -> f32 {
+        let df = self.df.get(token).copied().unwrap_or(0);
+        ((1.0 + self.docs as f32) / (1.0 + df as f32)).ln() + 1.0
+    }
+
+    fn vector(&self, s: &str) -> HashMap<String, f32> {
+        let mut tf: HashMap<String, f32> = HashMap::new();
+        for token in tokens(s) {
+            *tf.entry(token).or_insert(0.0) += 1.0;
+        }
+        for (token, value) in tf.iter_mut() {
+            *value *= self.idf(token);
+        }
+        let norm = tf.values().map(|v| v * v).sum::<f32>().sqrt();
+        if norm > 0.0 {
+            tf.values_mut().for_each(|v| *v /= norm);
+        }
+        tf
+    }
+}
+
+impl Calc for SoftTfIdf {
+    fn calc(&self, s1: String, s2: String) -> f32 {
+        let v1 = self.vector(&s1);
+        let v2 = self.vector(&s2);
+
+        if v1.is_empty() && v2.is_empty() {
+            return 1.0;
+        }
+
+        let mut similarity = 0.0f32;
+        for (w, weight1) in &v1 {
+            let best = v2
+                .iter()
+                .map(|(v, weight2)| (self.jaro.calc(w.clone(), v.clone()), weight2))
+                .max_by(|a, b| a.0.total_cmp(&b.0));
+            if let Some((sim, weight2)) = best
+                && sim >= self.threshold
+            {
+                similarity += weight1 * weight2 * sim;
+            }
+        }
+
+        let result = similarity;
+        result.clamp(0.0, 1.0)
+    }
+
+    fn get_weight(&self) -> f32 {
+        self.weight
+    }
+
+    fn get_label(&self) -> &str {
+        &self.label
+    }
+
+    fn get_cost(&self) -> f32 {
+        4.0
+    }
+}
@@ -0,0 +1,78 @@
+use super::prelude::*;
+use kenzu::Builder;
+use std::collections::BTreeSet;
+
+#[derive(Debug, Builder)]
+pub struct TokenSet {
+    #[set(value = 1.0)]
+    pub weight: f32,
+    pub label: String,
+    #[set(value = Levenshtein::new().to_box())]
+    pub inner: Box<dyn Calc>,
+}
+
+fn join(head: &str, tail: &str) -> String {
+    match (head.is_empty(), tail.is_empty()) {
+        (true, _) => tail.to_string(),
+        (_, true) => head.to_string(),
+        _ => format!("{head} {tail}"),
+    }
+}
+
+impl Calc for TokenSet {
+    fn calc(&self, s1: String, s2: String) -> f32 {
+        let set1: BTreeSet<&str> = s1.split_whitespace().collect();
+        let set2: BTreeSet<&str> = s2.split_whitespace().collect();
+
+        if set1.is_empty() || set2.is_empty() {
+            return self
+                .inner
+                .calc(s1.trim().into(), s2.trim().into())
+                .clamp(0.0, 1.0);
+        }
+
+        let common = set1
+            .intersection(&set2)
+            .cloned()
+            .collect::<Vec<_>>()
+            .join(" ");
+        let rest1 = set1
+            .difference(&set2)
+            .cloned()
+            .collect::<Vec<_>>()
+            .join(" ");
+        let rest2 = set2
+            .difference(&set1)
+            .cloned()
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        let combined1 = join(&common, &rest1);
+        let combined2 = join(&common, &rest2);
+
+        let mut best = self.inner.calc(combined1.clone(), combined2.clone());
+        if !common.is_empty() {
+            best = best
+                .max(self.inner.calc(common.clone(), combined1))
+                .max(self.inner.calc(common, combined2));
+        }
+
+        best.clamp(0.0, 1.0)
+    }
+
+    fn get_weight(&self) -> f32 {
+        self.weight
+    }
+
+    fn get_label(&self) -> &str {
+        &self.label
+    }
+
+    fn get_cost(&self) -> f32 {
+        1.0 + 3.0 * self.inner.get_cost()
+    }
+
+    fn get_algo_name(&self) -> String {
+        format!("TokenSet({})", self.inner.get_name())
+    }
+}
@@ -0,0 +1,43 @@
+use super::prelude::*;
+use kenzu::Builder;
+
+#[derive(Debug, Builder)]
+pub struct TokenSort {
+    #[set(value = 1.0)]
+    pub weight: f32,
+    pub label: String,
+    #[set(value = Levenshtein::new().to_box())]
+    pub inner: Box<dyn Calc>,
+}
+
+pub(crate) fn sorted_tokens(s: &str) -> Vec<&str> {
+    let mut tokens: Vec<&str> = s.split_whitespace().collect();
+    tokens.sort_unstable();
+    tokens
+}
+
+impl Calc for TokenSort {
+    fn calc(&self, s1: String, s2: String) -> f32 {
+        let t1 = sorted_tokens(&s1).join(" ");
+        let t2 = sorted_tokens(&s2).join(" ");
+
+        let result = self.inner.calc(t1, t2);
+        result.clamp(0.0, 1.0)
+    }
+
+    fn get_weight(&self) -> f32 {
+        self.weight
+    }
+
+    fn get_label(&self) -> &str {
+        &self.label
+    }
+
+    fn get_cost(&self) -> f32 {
+        1.0 + self.inner.get_cost()
+    }
+
+    fn get_algo_name(&self) -> String {
+        format!("TokenSort({})", self.inner.get_name())
+    }
+}
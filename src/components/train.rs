@@ -0,0 +1,191 @@
+use super::prelude::*;
+use kenzu::Builder;
+
+/// Learns flow weights and a decision threshold from labeled
+/// `(txt, term, matched)` pairs.
+///
+/// Every algorithm's score is a feature of a logistic regression whose
+/// weights are kept non-negative. With non-negative weights the decision
+/// boundary `bias + Σ wᵢ·sᵢ = 0` is exactly the weighted mean reaching
+/// `-bias / Σ wᵢ`, which becomes the threshold for `NekoSearch`. That only
+/// holds for `Aggregation::WeightedMean`, so the trained search always uses it.
+#[derive(Debug, Builder)]
+pub struct Trainer {
+    #[set(value=vec![
+       Jaro::new().to_box(),
+       Levenshtein::new().to_box(),
+       DamerauLevenshtein::new().to_box(),
+       Cosine::new().to_box(),
+       Jaccard::new().to_box(),
+       Metaphone::new().to_box(),
+       Lcs::new().to_box()
+    ])]
+    pub flow: Vec<Box<dyn Calc>>,
+    #[set(value = Normalization::Nfc)]
+    pub unicode: Normalization,
+    /// Fraction of each class kept aside to measure precision and recall.
+    #[set(value = 0.25)]
+    pub holdout: f32,
+    #[set(value = 2000_usize)]
+    pub epochs: usize,
+    #[set(value = 0.5)]
+    pub learning_rate: f32,
+    #[set(value = 42_u64)]
+    pub seed: u64,
+}
+
+#[derive(Debug)]
+pub struct Trained {
+    /// Search over the algorithms that got a weight, each wrapped in
+    /// `Weighted`, aggregated with `WeightedMean`.
+    pub neko: NekoSearch,
+    /// A pair is a match when the search scores at least this.
+    pub threshold: f32,
+    /// Learned weight per algorithm, largest scaled to 1.0.
+    pub weights: Vec<(String, f32)>,
+    /// Measured on the held-out pairs, `None` when they had no predicted or
+    /// no actual match to measure against.
+    pub precision: Option<f32>,
+    pub recall: Option<f32>,
+}
+
+impl Trained {
+    pub fn is_match(&self, txt: &str, term: &str) -> bool {
+        self.neko.compare(txt, term) >= self.threshold
+    }
+}
+
+fn shuffle(indices: &mut [usize], seed: &mut u64) {
+    for i in (1..indices.len()).rev() {
+        *seed ^= *seed << 13;
+        *seed ^= *seed >> 7;
+        *seed ^= *seed << 17;
+        indices.swap(i, (*seed % (i as u64 + 1)) as usize);
+    }
+}
+
+fn sigmoid(x: f64) -> f64 {
+    1.0 / (1.0 + (-x).exp())
+}
+
+impl Trainer {
+    fn split(&self, labels: &[bool]) -> (Vec<usize>, Vec<usize>) {
+        let mut seed = self.seed.max(1);
+        let (mut train, mut test) = (Vec::new(), Vec::new());
+        for class in [true, false] {
+            let mut indices: Vec<usize> =
+                (0..labels.len()).filter(|&i| labels[i] == class).collect();
+            shuffle(&mut indices, &mut seed);
+            let n = indices.len();
+            let held = ((n as f32 * self.holdout).round() as usize).min(n.saturating_sub(1));
+            test.extend_from_slice(&indices[..held]);
+            train.extend_from_slice(&indices[held..]);
+        }
+        (train, test)
+    }
+
+    fn logistic(
+        &self,
+        dims: usize,
+        features: &[Vec<f32>],
+        labels: &[bool],
+        rows: &[usize],
+    ) -> (Vec<f64>, f64) {
+        let mut weights = vec![1.0f64; dims];
+        let mut bias = 0.0f64;
+        if rows.is_empty() {
+            return (weights, -0.5 * dims as f64);
+        }
+
+        let rate = self.learning_rate as f64;
+        for _ in 0..self.epochs {
+            let mut grad = vec![0.0f64; dims];
+            let mut grad_bias = 0.0f64;
+            for &row in rows {
+                let x = &features[row];
+                let z = bias
+                    + weights
+                        .iter()
+                        .zip(x)
+                        .map(|(w, &s)| w * s as f64)
+                        .sum::<f64>();
+                let err = sigmoid(z) - if labels[row] { 1.0 } else { 0.0 };
+                for (g, &s) in grad.iter_mut().zip(x) {
+                    *g += err * s as f64;
+                }
+                grad_bias += err;
+            }
+            let n = rows.len() as f64;
+            for (w, g) in weights.iter_mut().zip(&grad) {
+                *w = (*w - rate * g / n).max(0.0);
+            }
+            bias -= rate * grad_bias / n;
+        }
+        (weights, bias)
+    }
+
+    pub fn fit<S: AsRef<str>>(mut self, pairs: &[(S, S, bool)]) -> Trained {
+        let dims = self.flow.len();
+        let mut neko = NekoSearch::new()
+            .flow(std::mem::take(&mut self.flow))
+            .unicode(self.unicode)
+            .aggregation(Aggregation::WeightedMean);
+        let features: Vec<Vec<f32>> = pairs
+            .iter()
+            .map(|(txt, term, _)| {
+                let run = neko.score(txt.as_ref(), term.as_ref());
+                run.results.iter().map(|find| find.score).collect()
+            })
+            .collect();
+        let labels: Vec<bool> = pairs.iter().map(|&(_, _, matched)| matched).collect();
+
+        let (train, test) = self.split(&labels);
+        let (weights, bias) = self.logistic(dims, &features, &labels, &train);
+
+        let total: f64 = weights.iter().sum();
+        let (weights, threshold) = if total > 0.0 {
+            let max = weights.iter().cloned().fold(0.0, f64::max);
+            let scaled: Vec<f32> = weights.iter().map(|w| (w / max) as f32).collect();
+            (scaled, (-bias / total).clamp(0.0, 1.0) as f32)
+        } else {
+            (vec![1.0; weights.len()], 0.5)
+        };
+
+        neko.flow = std::mem::take(&mut neko.flow)
+            .into_iter()
+            .zip(&weights)
+            .filter(|&(_, &weight)| weight > 0.0)
+            .map(|(inner, &weight)| Weighted::new().inner(inner).weight(weight).to_box())
+            .collect();
+        let names = neko.flow.iter().map(|algo| algo.get_name());
+        let learned = names
+            .zip(weights.iter().cloned().filter(|&weight| weight > 0.0))
+            .collect();
+
+        let mut trained = Trained {
+            neko,
+            threshold,
+            weights: learned,
+            precision: None,
+            recall: None,
+        };
+
+        let (mut tp, mut fp, mut fn_) = (0usize, 0usize, 0usize);
+        for &row in &test {
+            let (txt, term, matched) = &pairs[row];
+            match (trained.is_match(txt.as_ref(), term.as_ref()), *matched) {
+                (true, true) => tp += 1,
+                (true, false) => fp += 1,
+                (false, true) => fn_ += 1,
+                (false, false) => {}
+            }
+        }
+        if tp + fp > 0 {
+            trained.precision = Some(tp as f32 / (tp + fp) as f32);
+        }
+        if tp + fn_ > 0 {
+            trained.recall = Some(tp as f32 / (tp + fn_) as f32);
+        }
+        trained
+    }
+}
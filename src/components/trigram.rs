@@ -0,0 +1,204 @@
+use crate::Calc;
+use crate::components::types::TrigramMode;
+use kenzu::Builder;
+use std::collections::{HashMap, HashSet};
+
+const BOUND_LEFT: u8 = 0x01;
+const BOUND_RIGHT: u8 = 0x02;
+
+/// Trigram scorer replicating PostgreSQL's `pg_trgm`. In the word similarity
+/// modes the second string (`term` inside `NekoSearch`) is searched for in
+/// the first (`txt`), i.e. `calc(txt, term)` is `word_similarity(term, txt)`.
+#[derive(Debug, Builder, Clone)]
+pub struct Trigram {
+    #[set(value = 1.0)]
+    pub weight: f32,
+    pub label: String,
+    #[set(value = TrigramMode::Similarity)]
+    pub mode: TrigramMode,
+}
+
+fn generate_trgm(s: &str) -> Vec<(String, u8)> {
+    let mut trigrams = Vec::new();
+    for word in s.split(|c: char| !c.is_alphanumeric()) {
+        if word.is_empty() {
+            continue;
+        }
+        let padded: Vec<char> = "  "
+            .chars()
+            .chain(word.chars().flat_map(char::to_lowercase))
+            .chain(std::iter::once(' '))
+            .collect();
+        let last = padded.len() - 3;
+        for (i, w) in padded.windows(3).enumerate() {
+            let mut bound = 0;
+            if i == 0 {
+                bound |= BOUND_LEFT;
+            }
+            if i == last {
+                bound |= BOUND_RIGHT;
+            }
+            trigrams.push((w.iter().collect(), bound));
+        }
+    }
+    trigrams
+}
+
+fn calc_sml(count: usize, len1: usize, len2: usize) -> f32 {
+    count as f32 / (len1 + len2 - count) as f32
+}
+
+pub fn show_trgm(s: &str) -> Vec<String> {
+    let mut trigrams: Vec<String> = generate_trgm(s).into_iter().map(|(t, _)| t).collect();
+    trigrams.sort();
+    trigrams.dedup();
+    trigrams
+}
+
+pub fn trgm_similarity(s1: &str, s2: &str) -> f32 {
+    let set1: HashSet<String> = generate_trgm(s1).into_iter().map(|(t, _)| t).collect();
+    let set2: HashSet<String> = generate_trgm(s2).into_iter().map(|(t, _)| t).collect();
+    if set1.is_empty() || set2.is_empty() {
+        return 0.0;
+    }
+    let count = set1.intersection(&set2).count();
+    calc_sml(count, set1.len(), set2.len())
+}
+
+pub fn trgm_word_similarity(s1: &str, s2: &str) -> f32 {
+    calc_word_similarity(s1, s2, false)
+}
+
+pub fn trgm_strict_word_similarity(s1: &str, s2: &str) -> f32 {
+    calc_word_similarity(s1, s2, true)
+}
+
+fn calc_word_similarity(s1: &str, s2: &str, strict: bool) -> f32 {
+    let trg1 = generate_trgm(s1);
+    let trg2 = generate_trgm(s2);
+
+    let mut index: HashMap<&str, usize> = HashMap::new();
+    for (t, _) in trg1.iter().chain(trg2.iter()) {
+        let next = index.len();
+        index.entry(t.as_str()).or_insert(next);
+    }
+
+    let mut found = vec![false; index.len()];
+    for (t, _) in &trg1 {
+        found[index[t.as_str()]] = true;
+    }
+    let ulen1 = found.iter().filter(|f| **f).count();
+
+    let trg2indexes: Vec<usize> = trg2.iter().map(|(t, _)| index[t.as_str()]).collect();
+    let bounds: Vec<u8> = trg2.iter().map(|(_, b)| *b).collect();
+
+    iterate_word_similarity(&trg2indexes, &found, ulen1, &bounds, strict)
+}
+
+fn iterate_word_similarity(
+    trg2indexes: &[usize],
+    found: &[bool],
+    ulen1: usize,
+    bounds: &[u8],
+    strict: bool,
+) -> f32 {
+    let mut lastpos: Vec<Option<usize>> = vec![None; found.len()];
+    let mut ulen2 = 0usize;
+    let mut count = 0usize;
+    let mut lower: Option<usize> = if strict { Some(0) } else { None };
+    let mut smlr_max = 0.0f32;
+
+    for (i, &trgindex) in trg2indexes.iter().enumerate() {
+        if lower.is_some() || found[trgindex] {
+            if lastpos[trgindex].is_none() {
+                ulen2 += 1;
+                if found[trgindex] {
+                    count += 1;
+                }
+            }
+            lastpos[trgindex] = Some(i);
+        }
+
+        let is_upper = if strict {
+            bounds[i] & BOUND_RIGHT != 0
+        } else {
+            found[trgindex]
+        };
+        if !is_upper {
+            continue;
+        }
+
+        let upper = i;
+        let prev_lower = match lower {
+            Some(lower) => lower,
+            None => {
+                ulen2 = 1;
+                i
+            }
+        };
+
+        let mut smlr_cur = calc_sml(count, ulen1, ulen2);
+        let mut new_lower = prev_lower;
+        let mut tmp_count = count;
+        let mut tmp_ulen2 = ulen2;
+        for tmp_lower in prev_lower..=upper {
+            if !strict || bounds[tmp_lower] & BOUND_LEFT != 0 {
+                let smlr_tmp = calc_sml(tmp_count, ulen1, tmp_ulen2);
+                if smlr_tmp > smlr_cur {
+                    smlr_cur = smlr_tmp;
+                    ulen2 = tmp_ulen2;
+                    new_lower = tmp_lower;
+                    count = tmp_count;
+                }
+            }
+
+            let tmp_trgindex = trg2indexes[tmp_lower];
+            if lastpos[tmp_trgindex] == Some(tmp_lower) {
+                tmp_ulen2 -= 1;
+                if found[tmp_trgindex] {
+                    tmp_count -= 1;
+                }
+            }
+        }
+
+        smlr_max = smlr_max.max(smlr_cur);
+
+        for (tmp_lower, &tmp_trgindex) in trg2indexes
+            .iter()
+            .enumerate()
+            .take(new_lower)
+            .skip(prev_lower)
+        {
+            if lastpos[tmp_trgindex] == Some(tmp_lower) {
+                lastpos[tmp_trgindex] = None;
+            }
+        }
+        lower = Some(new_lower);
+    }
+
+    smlr_max
+}
+
+impl Calc for Trigram {
+    fn calc(&self, s1: String, s2: String) -> f32 {
+        let similarity = match self.mode {
+            TrigramMode::Similarity => trgm_similarity(&s1, &s2),
+            TrigramMode::WordSimilarity => trgm_word_similarity(&s2, &s1),
+            TrigramMode::StrictWordSimilarity => trgm_strict_word_similarity(&s2, &s1),
+        };
+        let result = similarity;
+        result.clamp(0.0, 1.0)
+    }
+
+    fn get_weight(&self) -> f32 {
+        self.weight
+    }
+
+    fn get_label(&self) -> &str {
+        &self.label
+    }
+
+    fn get_cost(&self) -> f32 {
+        1.0
+    }
+}
@@ -0,0 +1,71 @@
+use crate::Calc;
+use crate::components::jaccard::gram_set;
+use crate::components::types::{Gram, Normalization, Segmentation};
+use crate::components::unicode::normalize_unicode;
+use kenzu::Builder;
+
+/// Tversky index. `alpha` weighs grams only present in the first string
+/// (`txt` inside `NekoSearch`) and `beta` grams only present in the second
+/// (`term`). `alpha = beta = 0.5` is Sørensen-Dice, `alpha = beta = 1.0` is
+/// Jaccard, and `alpha = 0.0, beta = 1.0` scores how much of the term is
+/// contained in the text.
+#[derive(Debug, Builder, Clone)]
+pub struct Tversky {
+    #[set(value = 1.0)]
+    pub weight: f32,
+    pub label: String,
+    #[set(value = 2_usize)]
+    pub ngram: usize,
+    #[set(value = Gram::Char)]
+    pub gram: Gram,
+    #[set(value = Segmentation::Graphemes)]
+    pub segmentation: Segmentation,
+    #[set(value = Normalization::Nfc)]
+    pub unicode: Normalization,
+    #[set(value = 0.5)]
+    pub alpha: f32,
+    #[set(value = 0.5)]
+    pub beta: f32,
+}
+
+impl Calc for Tversky {
+    fn calc(&self, s1: String, s2: String) -> f32 {
+        let (s1, s2) = (
+            normalize_unicode(&s1, self.unicode),
+            normalize_unicode(&s2, self.unicode),
+        );
+        let set1 = gram_set(&s1, self.ngram, self.gram, self.segmentation);
+        let set2 = gram_set(&s2, self.ngram, self.gram, self.segmentation);
+
+        if set1.is_empty() && set2.is_empty() {
+            return 1.0;
+        }
+
+        let intersection = set1.intersection(&set2).count() as f64;
+        let only1 = set1.difference(&set2).count() as f64;
+        let only2 = set2.difference(&set1).count() as f64;
+        let alpha = self.alpha.max(0.0) as f64;
+        let beta = self.beta.max(0.0) as f64;
+
+        let denominator = intersection + alpha * only1 + beta * only2;
+        let similarity = if denominator == 0.0 {
+            0.0
+        } else {
+            intersection / denominator
+        };
+        let result = similarity as f32;
+        result.clamp(0.0, 1.0)
+    }
+
+    fn get_weight(&self) -> f32 {
+        self.weight
+    }
+
+    fn get_label(&self) -> &str {
+        &self.label
+    }
+
+    fn get_cost(&self) -> f32 {
+        1.0
+    }
+}
@@ -1 +1,51 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Gram {
+    #[default]
+    Char,
+    Word,
+}
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TrigramMode {
+    #[default]
+    Similarity,
+    WordSimilarity,
+    StrictWordSimilarity,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LengthMismatch {
+    Error,
+    #[default]
+    Pad,
+    Levenshtein,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Segmentation {
+    Chars,
+    #[default]
+    Graphemes,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Normalization {
+    None,
+    #[default]
+    Nfc,
+    Nfkc,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GateStage {
+    #[default]
+    Before,
+    After,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Side {
+    #[default]
+    First,
+    Second,
+}
@@ -0,0 +1,46 @@
+use crate::components::types::{Normalization, Segmentation};
+use std::borrow::Cow;
+use unicode_normalization::UnicodeNormalization;
+use unicode_segmentation::UnicodeSegmentation;
+
+pub fn normalize_unicode(s: &str, normalization: Normalization) -> Cow<'_, str> {
+    match normalization {
+        _ if s.is_ascii() => Cow::Borrowed(s),
+        Normalization::None => Cow::Borrowed(s),
+        Normalization::Nfc => Cow::Owned(s.nfc().collect()),
+        Normalization::Nfkc => Cow::Owned(s.nfkc().collect()),
+    }
+}
+
+/// Splits `s` into the comparison units used by the scorers: extended
+/// grapheme clusters by default, or single code points.
+pub fn segment(s: &str, segmentation: Segmentation) -> Vec<&str> {
+    match segmentation {
+        Segmentation::Graphemes => s.graphemes(true).collect(),
+        Segmentation::Chars => s
+            .char_indices()
+            .map(|(i, c)| &s[i..i + c.len_utf8()])
+            .collect(),
+    }
+}
+
+/// Byte offset of every unit boundary in `s`, including `0` and `s.len()`.
+pub(crate) fn boundaries(s: &str, segmentation: Segmentation) -> Vec<usize> {
+    let mut offsets: Vec<usize> = match segmentation {
+        Segmentation::Graphemes => s.grapheme_indices(true).map(|(i, _)| i).collect(),
+        Segmentation::Chars => s.char_indices().map(|(i, _)| i).collect(),
+    };
+    offsets.push(s.len());
+    offsets
+}
+
+/// First code point of a unit, used by the per-character cost models.
+pub(crate) fn base(unit: &str) -> char {
+    unit.chars().next().unwrap_or('\0')
+}
+
+/// Whether every comparison unit of `s` is a single ASCII byte, which lets
+/// the scorers work on `s.as_bytes()` directly. `"\r\n"` is one grapheme.
+pub(crate) fn is_byte_units(s: &str, segmentation: Segmentation) -> bool {
+    s.is_ascii() && (segmentation == Segmentation::Chars || !s.contains("\r\n"))
+}
@@ -0,0 +1,84 @@
+use crate::Calc;
+use crate::components::generic::levenshtein_similarity;
+use kenzu::Builder;
+
+/// Edit distance over the word sequence. Without `inner` a substituted word
+/// costs 1; with `inner` it costs `1 - inner.calc(word1, word2)`, so a typo
+/// inside a word only counts as a partial edit.
+#[derive(Debug, Builder)]
+pub struct WordLevenshtein {
+    #[set(value = 1.0)]
+    pub weight: f32,
+    pub label: String,
+    pub inner: Option<Box<dyn Calc>>,
+}
+
+impl WordLevenshtein {
+    fn partial_distance(&self, inner: &dyn Calc, a: &[&str], b: &[&str]) -> f64 {
+        let (len1, len2) = (a.len(), b.len());
+        let mut matrix = vec![vec![0.0f64; len2 + 1]; len1 + 1];
+
+        for (i, row) in matrix.iter_mut().enumerate() {
+            row[0] = i as f64;
+        }
+        for (j, cell) in matrix[0].iter_mut().enumerate() {
+            *cell = j as f64;
+        }
+
+        for i in 1..=len1 {
+            for j in 1..=len2 {
+                let cost = if a[i - 1] == b[j - 1] {
+                    0.0
+                } else {
+                    1.0 - inner
+                        .calc(a[i - 1].to_string(), b[j - 1].to_string())
+                        .clamp(0.0, 1.0) as f64
+                };
+                matrix[i][j] = (matrix[i - 1][j] + 1.0)
+                    .min(matrix[i][j - 1] + 1.0)
+                    .min(matrix[i - 1][j - 1] + cost);
+            }
+        }
+
+        matrix[len1][len2]
+    }
+}
+
+impl Calc for WordLevenshtein {
+    fn calc(&self, s1: String, s2: String) -> f32 {
+        let a: Vec<&str> = s1.split_whitespace().collect();
+        let b: Vec<&str> = s2.split_whitespace().collect();
+
+        let max_len = a.len().max(b.len());
+        if max_len == 0 {
+            return 1.0;
+        }
+
+        let similarity = match &self.inner {
+            Some(inner) => 1.0 - self.partial_distance(inner.as_ref(), &a, &b) / max_len as f64,
+            None => levenshtein_similarity(&a, &b),
+        };
+
+        let result = similarity as f32;
+        result.clamp(0.0, 1.0)
+    }
+
+    fn get_weight(&self) -> f32 {
+        self.weight
+    }
+
+    fn get_label(&self) -> &str {
+        &self.label
+    }
+
+    fn get_cost(&self) -> f32 {
+        3.0 * self.inner.as_ref().map_or(1.0, |inner| inner.get_cost())
+    }
+
+    fn get_algo_name(&self) -> String {
+        match &self.inner {
+            Some(inner) => format!("WordLevenshtein({})", inner.get_name()),
+            None => "WordLevenshtein".to_string(),
+        }
+    }
+}
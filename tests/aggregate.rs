@@ -0,0 +1,91 @@
+use nekosearch::components::prelude::*;
+
+fn neko(aggregation: Aggregation) -> NekoSearch {
+    NekoSearch::new()
+        .txt("Rust")
+        .term("Rost")
+        .flow(vec![
+            Levenshtein::new().to_box(),
+            Jaro::new().to_box(),
+            Metaphone::new().to_box(),
+        ])
+        .aggregation(aggregation)
+}
+
+fn scores(neko: &NekoSearch) -> Vec<f32> {
+    neko.results.iter().map(|find| find.score).collect()
+}
+
+#[test]
+fn test_default_is_weighted_mean() {
+    let mut default = neko(Aggregation::default());
+    let mut mean = neko(Aggregation::WeightedMean);
+    let score = default.find();
+    assert_eq!(score, mean.find());
+    let s = scores(&default);
+    assert!((score - s.iter().sum::<f32>() / s.len() as f32).abs() < 1e-6);
+}
+
+#[test]
+fn test_max_min_and_median() {
+    let mut max = neko(Aggregation::Max);
+    let mut min = neko(Aggregation::Min);
+    let mut median = neko(Aggregation::Median);
+    let (score, min, median) = (max.find(), min.find(), median.find());
+
+    let mut s = scores(&max);
+    s.sort_by(f32::total_cmp);
+    assert_eq!(score, s[2]);
+    assert_eq!(min, s[0]);
+    assert_eq!(median, s[1]);
+}
+
+#[test]
+fn test_zero_score_vetoes_geometric_and_harmonic() {
+    let flow = || vec![Jaro::new().to_box(), Jaccard::new().ngram(5_usize).to_box()];
+    for aggregation in [Aggregation::WeightedGeometric, Aggregation::Harmonic] {
+        let mut neko = NekoSearch::new()
+            .txt("abc")
+            .term("abd")
+            .flow(flow())
+            .aggregation(aggregation);
+        assert_eq!(neko.find(), 0.0);
+        assert!(neko.results[0].score > 0.5);
+    }
+}
+
+#[test]
+fn test_weighted_geometric_and_harmonic() {
+    let mut mean = neko(Aggregation::WeightedMean);
+    let mut geometric = neko(Aggregation::WeightedGeometric);
+    let mut harmonic = neko(Aggregation::Harmonic);
+    let (mean, geometric, harmonic) = (mean.find(), geometric.find(), harmonic.find());
+    assert!(mean >= geometric && geometric >= harmonic);
+    assert!(harmonic > 0.0);
+}
+
+#[test]
+fn test_custom_aggregator() {
+    let mut neko = neko(Aggregation::Custom(Aggregator::new(|results| {
+        results
+            .iter()
+            .find(|find| find.algo == "Metaphone")
+            .map_or(0.0, |find| find.score)
+    })));
+    let score = neko.find();
+    assert_eq!(score, neko.results[2].score);
+}
+
+#[test]
+fn test_zero_weight_is_ignored() {
+    let mut neko = NekoSearch::new()
+        .txt("Rust")
+        .term("Java")
+        .flow(vec![
+            Levenshtein::new().to_box(),
+            Jaro::new().weight(0.0).to_box(),
+        ])
+        .aggregation(Aggregation::Max);
+    let score = neko.find();
+    assert_eq!(score, neko.results[0].score);
+}
@@ -0,0 +1,121 @@
+use nekosearch::components::ascii::{lcs_ascii, levenshtein_ascii};
+use nekosearch::components::generic::{lcs, levenshtein};
+use nekosearch::components::prelude::*;
+
+fn corpus() -> Vec<String> {
+    let mut seed = 0x2545_f491_u64;
+    let mut next = move || {
+        seed ^= seed << 13;
+        seed ^= seed >> 7;
+        seed ^= seed << 17;
+        seed
+    };
+    let mut strings = vec![
+        String::new(),
+        "a".to_string(),
+        "martha".to_string(),
+        "the quick brown fox jumps over the lazy dog".to_string(),
+    ];
+    for len in [3, 8, 31, 63, 64, 65, 100, 150] {
+        for _ in 0..4 {
+            let s: String = (0..len)
+                .map(|_| (b'a' + (next() % 6) as u8) as char)
+                .collect();
+            strings.push(s);
+        }
+    }
+    strings
+}
+
+fn as_chars(s: &str) -> Vec<char> {
+    s.chars().collect()
+}
+
+#[test]
+fn test_bit_parallel_levenshtein_matches_dp() {
+    let strings = corpus();
+    for a in &strings {
+        for b in &strings {
+            assert_eq!(
+                levenshtein_ascii(a.as_bytes(), b.as_bytes()),
+                levenshtein(&as_chars(a), &as_chars(b)),
+                "{a:?} / {b:?}"
+            );
+        }
+    }
+}
+
+#[test]
+fn test_bit_parallel_lcs_matches_dp() {
+    let strings = corpus();
+    for a in &strings {
+        for b in &strings {
+            assert_eq!(
+                lcs_ascii(a.as_bytes(), b.as_bytes()),
+                lcs(&as_chars(a), &as_chars(b)),
+                "{a:?} / {b:?}"
+            );
+        }
+    }
+}
+
+#[test]
+fn test_fast_path_scores_are_identical() {
+    let strings = corpus();
+    let flow: Vec<(Box<dyn Calc>, Box<dyn Calc>)> = vec![
+        (
+            Levenshtein::new().to_box(),
+            Levenshtein::new().costs(Confusion::new()).to_box(),
+        ),
+        (
+            DamerauLevenshtein::new().to_box(),
+            DamerauLevenshtein::new().costs(Confusion::new()).to_box(),
+        ),
+    ];
+    for a in strings.iter().take(12) {
+        for b in strings.iter().take(12) {
+            for (fast, slow) in &flow {
+                let fast = fast.calc(a.clone(), b.clone());
+                let slow = slow.calc(a.clone(), b.clone());
+                assert!(
+                    (fast - slow).abs() < 1e-6,
+                    "{a:?} / {b:?}: {fast} != {slow}"
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn test_ascii_and_unicode_paths_agree() {
+    let pairs = [
+        ("martha", "marhta"),
+        ("dixon", "dicksonx"),
+        ("kitten", "sitting"),
+        ("rust", ""),
+    ];
+    let swap = |s: &str| s.replace('a', "ä").replace('i', "ï");
+    for (a, b) in pairs {
+        let flow: Vec<Box<dyn Calc>> = vec![
+            Levenshtein::new().to_box(),
+            DamerauLevenshtein::new().to_box(),
+            Jaro::new().to_box(),
+            Lcs::new().to_box(),
+            Jaccard::new().to_box(),
+            Cosine::new().to_box(),
+        ];
+        for algo in flow {
+            let ascii = algo.calc(a.into(), b.into());
+            let unicode = algo.calc(swap(a), swap(b));
+            assert_eq!(ascii, unicode, "{} {a:?} / {b:?}", algo.get_algo_name());
+        }
+    }
+}
+
+#[test]
+fn test_crlf_is_a_single_grapheme() {
+    let lev = Levenshtein::new();
+    assert_eq!(lev.calc("a\r\nb".into(), "a\nb".into()), 2.0 / 3.0);
+    let chars = Levenshtein::new().segmentation(Segmentation::Chars);
+    assert_eq!(chars.calc("a\r\nb".into(), "a\nb".into()), 0.75);
+}
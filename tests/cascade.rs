@@ -0,0 +1,160 @@
+use nekosearch::components::prelude::*;
+
+#[test]
+fn test_cheapest_first() {
+    let mut neko = NekoSearch::new()
+        .txt("martha")
+        .term("marhta")
+        .flow(vec![
+            SmithWaterman::new().to_box(),
+            DamerauLevenshtein::new().to_box(),
+            Jaro::new().to_box(),
+            Jaccard::new().to_box(),
+        ])
+        .cascade(0.0);
+    neko.find();
+    let algos: Vec<&str> = neko.results.iter().map(|find| find.algo.as_str()).collect();
+    assert_eq!(
+        algos,
+        ["Jaccard", "Jaro", "DamerauLevenshtein", "SmithWaterman"]
+    );
+    assert!(neko.skipped.is_empty());
+}
+
+#[test]
+fn test_prunes_unreachable_threshold() {
+    let mut neko = NekoSearch::new().txt("Rust").term("Java").cascade(0.8);
+    let score = neko.find();
+
+    assert!(score < 0.8);
+    assert!(
+        neko.bound
+            .is_some_and(|bound| bound < 0.8 && score <= bound)
+    );
+    assert!(!neko.skipped.is_empty());
+    assert_eq!(neko.results.len() + neko.skipped.len(), neko.flow.len());
+    assert!(neko.skipped.contains(&"DamerauLevenshtein".to_string()));
+}
+
+#[test]
+fn test_bound_is_never_below_full_score() {
+    for (txt, term) in [
+        ("Rust", "Java"),
+        ("Python", "Piton"),
+        ("development", "dev"),
+    ] {
+        let mut full = NekoSearch::new().txt(txt).term(term);
+        let mut cascade = NekoSearch::new().txt(txt).term(term).cascade(0.9);
+        let (full, score) = (full.find(), cascade.find());
+        let bound = cascade.bound.unwrap_or(score);
+        assert!(bound >= full - 1e-6, "{txt}/{term}: {bound} < {full}");
+        assert!(score <= bound + 1e-6, "{txt}/{term}: {score} > {bound}");
+    }
+}
+
+#[test]
+fn test_reachable_threshold_runs_everything() {
+    let mut full = NekoSearch::new().txt("martha").term("marhta");
+    let mut cascade = NekoSearch::new().txt("martha").term("marhta").cascade(0.5);
+    let score = cascade.find();
+
+    assert!((score - full.find()).abs() < 1e-6);
+    assert!(cascade.skipped.is_empty());
+    assert!(cascade.bound.is_none());
+    assert_eq!(cascade.results.len(), 7);
+}
+
+#[test]
+fn test_pruned_score_is_the_aggregate_of_what_ran() {
+    let mut neko = NekoSearch::new().txt("abc").term("xyz").cascade(0.9);
+    let score = neko.find();
+    assert!(!neko.skipped.is_empty());
+    assert!((score - Aggregation::WeightedMean.apply(&neko.results)).abs() < 1e-6);
+}
+
+#[test]
+fn test_after_gates_run_on_pruned_results() {
+    let mut neko = NekoSearch::new()
+        .txt("abc")
+        .term("xyz")
+        .gates(vec![Gate::after("no_overlap", 0.0, |e| {
+            e.score < Some(0.5)
+        })])
+        .cascade(0.9);
+    assert_eq!(neko.find(), 0.0);
+    assert!(!neko.skipped.is_empty());
+    assert_eq!(neko.decision.as_deref(), Some("no_overlap"));
+}
+
+#[test]
+fn test_last_algorithm_is_not_pruned() {
+    let mut neko = NekoSearch::new()
+        .txt("abc")
+        .term("xyz")
+        .flow(vec![Jaccard::new().to_box()])
+        .cascade(0.9);
+    neko.find();
+    assert!(neko.bound.is_none());
+    assert!(neko.skipped.is_empty());
+}
+
+#[test]
+fn test_custom_aggregation_is_not_pruned() {
+    let mut neko = NekoSearch::new()
+        .txt("Rust")
+        .term("Java")
+        .aggregation(Aggregation::Custom(Aggregator::new(|results| {
+            results.iter().map(|find| find.score).fold(0.0, f32::max)
+        })))
+        .cascade(0.99);
+    neko.find();
+    assert!(neko.skipped.is_empty());
+}
+
+#[test]
+fn test_gate_skips_are_reported() {
+    let mut neko = NekoSearch::new()
+        .txt("rust")
+        .term("rust")
+        .gates(vec![Gate::exact_match()]);
+    neko.find();
+    assert_eq!(neko.skipped.len(), 7);
+    assert_eq!(neko.skipped[0], "Jaro");
+}
+
+#[test]
+fn test_gate_outcome_keeps_the_flow_running() {
+    let mut neko = NekoSearch::new()
+        .txt("Kathryn")
+        .term("Cathrine")
+        .gates(vec![Gate::before("phonetic", 1.0, |e| {
+            e.score_of("Metaphone") == Some(1.0)
+        })])
+        .cascade(0.99);
+    assert_eq!(neko.find(), 1.0);
+    assert_eq!(neko.decision.as_deref(), Some("phonetic"));
+    assert!(neko.bound.is_none());
+}
+
+#[test]
+fn test_exact_match_does_not_block_pruning() {
+    let mut plain = NekoSearch::new().txt("martha").term("xyzzy").cascade(0.8);
+    let mut gated = NekoSearch::new()
+        .txt("martha")
+        .term("xyzzy")
+        .gates(vec![Gate::exact_match()])
+        .cascade(0.8);
+    assert_eq!(gated.find(), plain.find());
+    assert!(!gated.skipped.is_empty());
+    assert_eq!(gated.skipped, plain.skipped);
+    assert!(gated.bound.is_some_and(|bound| bound < 0.8));
+    assert_eq!(gated.decision, None);
+
+    let mut equal = NekoSearch::new()
+        .txt("martha")
+        .term("Martha")
+        .gates(vec![Gate::exact_match()])
+        .cascade(0.8);
+    assert_eq!(equal.find(), 1.0);
+    assert_eq!(equal.decision.as_deref(), Some("exact_match"));
+}
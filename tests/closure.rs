@@ -0,0 +1,71 @@
+use nekosearch::components::prelude::*;
+
+#[test]
+fn test_closure_calc() {
+    let same_length = Closure::named("same_length", |s1, s2| {
+        if s1.chars().count() == s2.chars().count() {
+            1.0
+        } else {
+            0.0
+        }
+    });
+    assert_eq!(same_length.calc("rust".into(), "java".into()), 1.0);
+    assert_eq!(same_length.calc("rust".into(), "go".into()), 0.0);
+    assert_eq!(same_length.get_name(), "same_length");
+}
+
+#[test]
+fn test_closure_score_is_clamped() {
+    let wild = Closure::named("wild", |_, _| 3.5).weight(2.0);
+    assert_eq!(wild.calc("a".into(), "b".into()), 1.0);
+    assert_eq!(wild.get_weight(), 2.0);
+}
+
+#[test]
+fn test_unlabeled_names_fall_back_to_type() {
+    assert_eq!(Jaro::new().get_name(), "Jaro");
+    assert_eq!(Closure::new().get_name(), "Closure");
+    assert_eq!(
+        TokenSort::new().inner(Jaro::new().to_box()).get_name(),
+        "TokenSort(Jaro)"
+    );
+}
+
+#[test]
+fn test_labels_make_results_unique() {
+    let mut neko = NekoSearch::new()
+        .txt("São Paulo")
+        .term("Sao Paulo")
+        .flow(vec![
+            Jaccard::new()
+                .ngram(2_usize)
+                .label("jaccard_bigram")
+                .to_box(),
+            Jaccard::new()
+                .ngram(3_usize)
+                .label("jaccard_trigram")
+                .to_box(),
+            Closure::named("prefix", |s1, s2| {
+                let common = s1.chars().zip(s2.chars()).take_while(|(a, b)| a == b);
+                common.count() as f32 / s1.chars().count().max(1) as f32
+            })
+            .to_box(),
+        ]);
+    neko.find();
+
+    let algos: Vec<&str> = neko.results.iter().map(|find| find.algo.as_str()).collect();
+    assert_eq!(algos, ["jaccard_bigram", "jaccard_trigram", "prefix"]);
+}
+
+#[test]
+fn test_labels_inside_wrappers() {
+    let inner = Levenshtein::new().label("typo");
+    let partial = Partial::new().inner(inner.to_box());
+    assert_eq!(partial.get_name(), "Partial(typo)");
+
+    let labeled = Max::new()
+        .flow(vec![Jaro::new().to_box(), Metaphone::new().to_box()])
+        .label("name");
+    assert_eq!(labeled.get_name(), "name");
+    assert_eq!(labeled.get_algo_name(), "Max(Jaro, Metaphone)");
+}
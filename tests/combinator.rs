@@ -0,0 +1,81 @@
+use nekosearch::components::prelude::*;
+
+fn score(algo: &dyn Calc, s1: &str, s2: &str) -> f32 {
+    algo.calc(s1.into(), s2.into())
+}
+
+#[test]
+fn test_max_and_min() {
+    let flow = || vec![Jaro::new().to_box(), Metaphone::new().to_box()];
+    let max = Max::new().flow(flow());
+    let min = Min::new().flow(flow());
+
+    let jaro = score(&Jaro::new(), "smith", "smyth");
+    assert!(jaro < 1.0);
+    assert_eq!(score(&max, "smith", "smyth"), 1.0);
+    assert_eq!(score(&min, "smith", "smyth"), jaro);
+    assert_eq!(max.get_algo_name(), "Max(Jaro, Metaphone)");
+}
+
+#[test]
+fn test_weighted_mean_and_product() {
+    let jaro = score(&Jaro::new(), "martha", "marhta");
+    let lev = score(&Levenshtein::new(), "martha", "marhta");
+
+    let mean = WeightedMean::new().flow(vec![
+        Jaro::new().weight(3.0).to_box(),
+        Levenshtein::new().to_box(),
+    ]);
+    let product = Product::new().flow(vec![Jaro::new().to_box(), Levenshtein::new().to_box()]);
+
+    assert!((score(&mean, "martha", "marhta") - (3.0 * jaro + lev) / 4.0).abs() < 1e-6);
+    assert!((score(&product, "martha", "marhta") - jaro * lev).abs() < 1e-6);
+}
+
+#[test]
+fn test_threshold_gate() {
+    let gate = Threshold::new().inner(Jaro::new().to_box()).threshold(0.9);
+    assert_eq!(score(&gate, "rust", "java"), 0.0);
+    assert_eq!(score(&gate, "rust", "rust"), 1.0);
+}
+
+#[test]
+fn test_if_else_on_predicate() {
+    let short = IfElse::new()
+        .predicate(Predicate::new(|s1, s2| s1.len().max(s2.len()) <= 4))
+        .then(Jaro::new().to_box())
+        .otherwise(TokenSort::new().to_box());
+
+    assert_eq!(score(&short, "ab", "ba"), score(&Jaro::new(), "ab", "ba"));
+    assert_eq!(score(&short, "brown quick", "quick brown"), 1.0);
+}
+
+#[test]
+fn test_nested_pipeline() {
+    let phonetic = Max::new().flow(vec![Jaro::new().to_box(), Metaphone::new().to_box()]);
+    let mut neko = NekoSearch::new()
+        .txt("Smith")
+        .term("Smyth")
+        .flow(vec![phonetic.to_box(), Jaccard::new().to_box()]);
+    let result = neko.find();
+
+    let jaccard = score(&Jaccard::new(), "smith", "smyth");
+    assert!((result - (1.0 + jaccard) / 2.0).abs() < 1e-6);
+}
+
+#[test]
+fn test_neko_search_as_sub_flow() {
+    let inner = NekoSearch::new()
+        .flow(vec![Jaro::new().to_box(), Levenshtein::new().to_box()])
+        .weight(2.0);
+    let mut neko = NekoSearch::new()
+        .txt("Python")
+        .term("Piton")
+        .flow(vec![inner.to_box(), Metaphone::new().to_box()]);
+    let result = neko.find();
+
+    assert_eq!(neko.results[0].algo, "NekoSearch(Jaro, Levenshtein)");
+    assert_eq!(neko.results[0].weight, 2.0);
+    let expected = (2.0 * neko.results[0].score + neko.results[1].score) / 3.0;
+    assert!((result - expected).abs() < 1e-6);
+}
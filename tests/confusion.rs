@@ -0,0 +1,71 @@
+use nekosearch::components::prelude::*;
+
+#[test]
+fn test_single_character_confusions() {
+    let lev = Levenshtein::new().costs(Confusion::ocr());
+    let ocr = lev.calc("INV0ICE 1O5".into(), "INVOICE lO5".into());
+    let plain = Levenshtein::new().calc("INV0ICE 1O5".into(), "INVOICE lO5".into());
+    assert!(ocr > plain, "ocr = {}, plain = {}", ocr, plain);
+    assert!((ocr - (1.0 - 0.4 / 11.0)).abs() < 1e-6, "ocr = {}", ocr);
+}
+
+#[test]
+fn test_multi_character_confusions() {
+    let lev = Levenshtein::new().costs(Confusion::ocr());
+    let similarity = lev.calc("modern".into(), "rnodern".into());
+    assert!(
+        (similarity - (1.0 - 0.2 / 7.0)).abs() < 1e-6,
+        "similarity = {}",
+        similarity
+    );
+    let reversed = lev.calc("rnodern".into(), "modern".into());
+    assert_eq!(similarity, reversed);
+}
+
+#[test]
+fn test_damerau_levenshtein_with_confusions() {
+    let dl = DamerauLevenshtein::new().costs(Confusion::ocr());
+    let similarity = dl.calc("B00K".into(), "800K".into());
+    assert!(
+        (similarity - 0.95).abs() < 1e-6,
+        "similarity = {}",
+        similarity
+    );
+}
+
+#[test]
+fn test_ocr_inside_search() {
+    let mut neko = NekoSearch::new()
+        .txt("INV0ICE 8ANK")
+        .term("INVOICE BANK")
+        .flow(vec![Levenshtein::new().costs(Confusion::ocr()).to_box()]);
+    let similarity = neko.find();
+    assert_eq!(neko.results[0].txt, "inv0ice 8ank");
+    assert!(
+        (similarity - (1.0 - 0.5 / 12.0)).abs() < 1e-6,
+        "similarity = {}",
+        similarity
+    );
+}
+
+#[test]
+fn test_loadable_tables() {
+    let table = "# custom table\nç c 0.1\nss ß 0.2\n";
+    let confusion = Confusion::parse(table).unwrap();
+    assert_eq!(confusion.substitution('c', 'ç'), 0.1);
+    assert_eq!(confusion.rules().len(), 2);
+
+    let lev = Levenshtein::new().costs(confusion);
+    let similarity = lev.calc("straße".into(), "strasse".into());
+    assert!(
+        (similarity - (1.0 - 0.2 / 7.0)).abs() < 1e-6,
+        "similarity = {}",
+        similarity
+    );
+}
+
+#[test]
+fn test_invalid_tables() {
+    assert!(Confusion::parse("rn m").is_err());
+    assert!(Confusion::parse("rn m cheap").is_err());
+}
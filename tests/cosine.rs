@@ -79,3 +79,15 @@ fn test_long_strings() {
         similarity
     );
 }
+
+#[test]
+fn test_padded_boundaries() {
+    let plain = Cosine::new().calc("martha".into(), "artha".into());
+    let padded = Cosine::new()
+        .pad(true)
+        .calc("martha".into(), "artha".into());
+    assert!(padded < plain, "padded = {}, plain = {}", padded, plain);
+    let custom = Cosine::new().pad(true).marker('#');
+    assert_eq!(custom.calc("rust".into(), "rust".into()), 1.0);
+    assert_eq!(custom.calc("abc".into(), "".into()), 0.0);
+}
@@ -0,0 +1,59 @@
+use nekosearch::components::prelude::*;
+
+#[test]
+fn test_reference_distances() {
+    let editex = Editex::new();
+    assert_eq!(editex.distance("cat", "hat"), 2);
+    assert_eq!(editex.distance("Niall", "Neil"), 2);
+    assert_eq!(editex.distance("aluminum", "Catalan"), 12);
+    assert_eq!(editex.distance("ATCG", "TAGC"), 6);
+}
+
+#[test]
+fn test_identical_strings() {
+    let editex = Editex::new();
+    assert_eq!(editex.calc("martha".into(), "martha".into()), 1.0);
+}
+
+#[test]
+fn test_phonetic_substitution_is_cheaper() {
+    let editex = Editex::new();
+    let same_group = editex.calc("kat".into(), "cat".into());
+    let other_group = editex.calc("bat".into(), "cat".into());
+    assert!(
+        same_group > other_group,
+        "same = {}, other = {}",
+        same_group,
+        other_group
+    );
+    assert!((same_group - 5.0 / 6.0).abs() < 1e-6);
+}
+
+#[test]
+fn test_portuguese_groups() {
+    let english = Editex::new().distance("caça", "cassa");
+    let portuguese = Editex::portuguese().distance("caça", "cassa");
+    assert!(
+        portuguese < english,
+        "pt = {}, en = {}",
+        portuguese,
+        english
+    );
+    assert_eq!(Editex::portuguese().distance("você", "voce"), 1);
+}
+
+#[test]
+fn test_portuguese_groups_are_single_letters() {
+    let portuguese = Editex::portuguese();
+    let h = portuguese.calc("caro".into(), "haro".into());
+    let t = portuguese.calc("caro".into(), "taro".into());
+    assert!(h <= t, "caro/haro = {}, caro/taro = {}", h, t);
+    assert_eq!(portuguese.distance("xá", "há"), 2);
+}
+
+#[test]
+fn test_empty_strings() {
+    let editex = Editex::new();
+    assert_eq!(editex.calc("".into(), "".into()), 1.0);
+    assert_eq!(editex.calc("abc".into(), "".into()), 0.0);
+}
@@ -35,3 +35,19 @@ fn test_metaphone_perfect_match() {
 fn test_metaphone_no_match() {
     assert_eq!(run_test("qwert", "zxcvb"), 0.0);
 }
+
+#[test]
+fn test_metaphone_accented_letters() {
+    assert_eq!(run_test("café", "cafe"), 1.0);
+    assert_eq!(run_test("cafe\u{301}", "cafe"), 1.0);
+    assert_eq!(run_test("açaí", "acai"), 1.0);
+}
+
+#[test]
+fn test_metaphone_non_latin_scripts() {
+    assert_eq!(run_test("Москва", "Αθήνα"), 0.0);
+    assert_eq!(run_test("東京", "北京"), 0.0);
+    assert_eq!(run_test("Москва", "Москва"), 0.0);
+    assert_eq!(run_test("Straße", "Strasse"), 1.0);
+    assert_eq!(run_test("Łódź", "Lodz"), 1.0);
+}
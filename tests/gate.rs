@@ -0,0 +1,89 @@
+use nekosearch::components::prelude::*;
+
+fn flow() -> Vec<Box<dyn Calc>> {
+    vec![
+        Metaphone::new().to_box(),
+        Levenshtein::new().to_box(),
+        Jaro::new().to_box(),
+    ]
+}
+
+#[test]
+fn test_exact_match_short_circuits() {
+    let mut neko = NekoSearch::new()
+        .txt("Rust!")
+        .term("rust")
+        .flow(flow())
+        .gates(vec![Gate::exact_match()]);
+    assert_eq!(neko.find(), 1.0);
+    assert!(neko.results.is_empty());
+    assert_eq!(neko.decision.as_deref(), Some("exact_match"));
+}
+
+#[test]
+fn test_veto_skips_remaining_algorithms() {
+    let veto = Gate::before("phonetic_veto", 0.0, |e| {
+        e.score_of("Metaphone") == Some(0.0) && e.score_of("Levenshtein").is_some_and(|s| s < 0.5)
+    });
+    let mut neko = NekoSearch::new()
+        .txt("Rust")
+        .term("Java")
+        .flow(flow())
+        .gates(vec![veto]);
+    assert_eq!(neko.find(), 0.0);
+    assert_eq!(neko.results.len(), 2);
+    assert_eq!(neko.decision.as_deref(), Some("phonetic_veto"));
+}
+
+#[test]
+fn test_gate_that_does_not_fire() {
+    let veto = Gate::before("phonetic_veto", 0.0, |e| {
+        e.score_of("Metaphone") == Some(0.0)
+    });
+    let mut gated = NekoSearch::new()
+        .txt("Smith")
+        .term("Smyth")
+        .flow(flow())
+        .gates(vec![veto]);
+    let mut plain = NekoSearch::new().txt("Smith").term("Smyth").flow(flow());
+
+    assert_eq!(gated.find(), plain.find());
+    assert_eq!(gated.results.len(), 3);
+    assert_eq!(gated.decision, None);
+}
+
+#[test]
+fn test_after_gate_sees_aggregate() {
+    let floor = Gate::after("floor", 0.0, |e| e.score.is_some_and(|s| s < 0.5));
+    let mut low = NekoSearch::new()
+        .txt("Rust")
+        .term("C++")
+        .flow(flow())
+        .gates(vec![floor.clone()]);
+    let mut high = NekoSearch::new()
+        .txt("martha")
+        .term("marhta")
+        .flow(flow())
+        .gates(vec![floor]);
+
+    assert_eq!(low.find(), 0.0);
+    assert_eq!(low.results.len(), 3);
+    assert_eq!(low.decision.as_deref(), Some("floor"));
+    assert!(high.find() > 0.5);
+    assert_eq!(high.decision, None);
+}
+
+#[test]
+fn test_first_matching_gate_wins() {
+    let mut neko = NekoSearch::new()
+        .txt("rust")
+        .term("rust")
+        .flow(flow())
+        .gates(vec![
+            Gate::before("never", 0.0, |_| false),
+            Gate::exact_match(),
+            Gate::before("also_exact", 0.5, |e| e.txt == e.term),
+        ]);
+    assert_eq!(neko.find(), 1.0);
+    assert_eq!(neko.decision.as_deref(), Some("exact_match"));
+}
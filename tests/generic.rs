@@ -0,0 +1,64 @@
+use nekosearch::components::generic::*;
+use nekosearch::components::prelude::*;
+
+#[test]
+fn test_word_level_levenshtein() {
+    let a: Vec<&str> = "the quick brown fox".split(' ').collect();
+    let b: Vec<&str> = "the quikc brown fx".split(' ').collect();
+    assert_eq!(levenshtein(&a, &b), 2);
+    assert_eq!(levenshtein_similarity(&a, &b), 0.5);
+}
+
+#[test]
+fn test_byte_level_distances() {
+    assert_eq!(levenshtein(b"kitten", b"sitting"), 3);
+    assert_eq!(damerau_levenshtein(b"martha", b"marhta"), 1);
+    assert_eq!(lcs(b"GATTACA", b"GCATAG"), 4);
+}
+
+#[test]
+fn test_product_code_lists() {
+    let a = [1042_u32, 2210, 3300, 4011];
+    let b = [1042_u32, 3300, 2210, 4011];
+    assert_eq!(levenshtein(&a, &b), 2);
+    assert_eq!(damerau_levenshtein(&a, &b), 1);
+    assert_eq!(lcs_similarity(&a, &b), 0.75);
+}
+
+#[test]
+fn test_generic_jaro() {
+    let a: Vec<char> = "martha".chars().collect();
+    let b: Vec<char> = "marhta".chars().collect();
+    assert!((jaro(&a, &b) - 0.944444).abs() < 1e-6);
+    assert!((jaro(b"martha", b"marhta") - jaro(&a, &b)).abs() < 1e-12);
+    assert_eq!(
+        jaro_winkler(&a, &b, 4) as f32,
+        Jaro::new().calc("martha".into(), "marhta".into())
+    );
+}
+
+#[test]
+fn test_wrappers_match_generic_versions() {
+    let pairs = [
+        ("kitten", "sitting"),
+        ("açaí", "acai"),
+        ("", "abc"),
+        ("", ""),
+    ];
+    for (s1, s2) in pairs {
+        let a: Vec<char> = s1.chars().collect();
+        let b: Vec<char> = s2.chars().collect();
+        assert_eq!(
+            Levenshtein::new().calc(s1.into(), s2.into()),
+            levenshtein_similarity(&a, &b) as f32
+        );
+        assert_eq!(
+            DamerauLevenshtein::new().calc(s1.into(), s2.into()),
+            damerau_levenshtein_similarity(&a, &b) as f32
+        );
+        assert_eq!(
+            Lcs::new().calc(s1.into(), s2.into()),
+            lcs_similarity(&a, &b) as f32
+        );
+    }
+}
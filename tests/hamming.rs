@@ -0,0 +1,64 @@
+use nekosearch::components::prelude::*;
+
+#[test]
+fn test_identical_codes() {
+    let hamming = Hamming::new();
+    assert_eq!(hamming.calc("ABC1D23".into(), "ABC1D23".into()), 1.0);
+}
+
+#[test]
+fn test_single_substitution() {
+    let hamming = Hamming::new();
+    let similarity = hamming.calc("ABC1D23".into(), "ABC1D28".into());
+    assert!(
+        (similarity - 6.0 / 7.0).abs() < 1e-6,
+        "similarity = {}",
+        similarity
+    );
+}
+
+#[test]
+fn test_shift_is_not_forgiven() {
+    let hamming = Hamming::new().calc("12345678".into(), "23456781".into());
+    let levenshtein = Levenshtein::new().calc("12345678".into(), "23456781".into());
+    assert_eq!(hamming, 0.0);
+    assert!(levenshtein > 0.7, "levenshtein = {}", levenshtein);
+}
+
+#[test]
+fn test_position_weights() {
+    let hamming =
+        Hamming::new().positions(vec![1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 5.0, 5.0]);
+    let check_digit = hamming.calc("12345678909".into(), "12345678900".into());
+    let body = hamming.calc("12345678909".into(), "02345678909".into());
+    assert!(
+        check_digit < body,
+        "check = {}, body = {}",
+        check_digit,
+        body
+    );
+    assert!((check_digit - 14.0 / 19.0).abs() < 1e-6);
+}
+
+#[test]
+fn test_unequal_lengths() {
+    let pad = Hamming::new();
+    assert_eq!(pad.calc("1234".into(), "12".into()), 0.5);
+
+    let error = Hamming::new().mismatch(LengthMismatch::Error);
+    assert!(error.similarity("1234", "12").is_err());
+    assert_eq!(error.calc("1234".into(), "12".into()), 0.0);
+
+    let fallback = Hamming::new().mismatch(LengthMismatch::Levenshtein);
+    assert_eq!(
+        fallback.calc("01234".into(), "1234".into()),
+        Levenshtein::new().calc("01234".into(), "1234".into())
+    );
+}
+
+#[test]
+fn test_empty_strings() {
+    let hamming = Hamming::new();
+    assert_eq!(hamming.calc("".into(), "".into()), 1.0);
+    assert_eq!(hamming.calc("abc".into(), "".into()), 0.0);
+}
@@ -75,3 +75,22 @@ fn test_long_strings() {
         similarity
     );
 }
+
+#[test]
+fn test_padded_single_characters() {
+    let plain = Jaccard::new().calc("a".into(), "ab".into());
+    let padded = Jaccard::new().pad(true).calc("a".into(), "ab".into());
+    assert_eq!(plain, 0.0);
+    assert!((padded - 0.25).abs() < 1e-6, "similarity = {}", padded);
+}
+
+#[test]
+fn test_padded_boundaries() {
+    let jaccard = Jaccard::new().ngram(3_usize).pad(true);
+    let same_edges = jaccard.calc("martha".into(), "marxxa".into());
+    let shifted = jaccard.calc("martha".into(), "xmartha".into());
+    assert!(same_edges > 0.0, "similarity = {}", same_edges);
+    assert!(shifted < 0.7, "similarity = {}", shifted);
+    assert_eq!(jaccard.calc("".into(), "".into()), 1.0);
+    assert_eq!(jaccard.calc("abc".into(), "".into()), 0.0);
+}
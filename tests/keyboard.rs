@@ -0,0 +1,58 @@
+use nekosearch::components::prelude::*;
+
+#[test]
+fn test_adjacent_keys_are_cheaper() {
+    let lev = Levenshtein::new().costs(Keyboard::qwerty());
+    let near = lev.calc("Rust".into(), "Rist".into());
+    let far = lev.calc("Rust".into(), "Rzst".into());
+    assert!(near > far, "near = {}, far = {}", near, far);
+    assert_eq!(near, 0.875);
+    assert_eq!(far, 0.75);
+}
+
+#[test]
+fn test_uniform_costs_unchanged() {
+    let plain = Levenshtein::new();
+    assert_eq!(
+        plain.calc("Rust".into(), "Rist".into()),
+        plain.calc("Rust".into(), "Rzst".into())
+    );
+}
+
+#[test]
+fn test_damerau_levenshtein_with_layout() {
+    let dl = DamerauLevenshtein::new().costs(Keyboard::dvorak());
+    let near = dl.calc("hello".into(), "hullo".into());
+    let far = dl.calc("hello".into(), "hzllo".into());
+    assert!(near > far, "near = {}, far = {}", near, far);
+    assert_eq!(dl.calc("martha".into(), "marhta".into()), 1.0 - 1.0 / 6.0);
+}
+
+#[test]
+fn test_builtin_layouts() {
+    assert_eq!(Keyboard::azerty().substitution('a', 'z'), 0.5);
+    assert_eq!(Keyboard::qwerty().substitution('a', 'z'), 0.5);
+    assert_eq!(Keyboard::abnt2().substitution('l', 'ç'), 0.5);
+    assert_eq!(Keyboard::phone().substitution('a', 'c'), 0.5);
+    assert_eq!(Keyboard::phone().substitution('a', 'w'), 1.0);
+    assert_eq!(Keyboard::qwerty().substitution('a', 'é'), 1.0);
+}
+
+#[test]
+fn test_custom_layout() {
+    assert!(Keyboard::layout("tiny").is_none());
+    assert_eq!(Keyboard::layout("QWERTY"), Some(Keyboard::qwerty()));
+
+    let tiny = Keyboard::from_rows("tiny", &[(0.0, "abc"), (0.0, "def")]).adjacent(0.2);
+    let lev = Levenshtein::new().costs(tiny);
+    assert_eq!(lev.calc("ab".into(), "ae".into()), 0.9);
+}
+
+#[test]
+fn test_adjacent_cost_is_bounded() {
+    let negative = Keyboard::qwerty().adjacent(-2.0);
+    assert_eq!(negative.substitution('q', 'w'), 0.0);
+    let mut raw = Keyboard::qwerty();
+    raw.adjacent = 3.0;
+    assert_eq!(raw.substitution('q', 'w'), 1.0);
+}
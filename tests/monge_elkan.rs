@@ -0,0 +1,45 @@
+use nekosearch::components::prelude::*;
+
+#[test]
+fn test_reordered_words() {
+    let me = MongeElkan::new();
+    let similarity = me.calc("silva joão".into(), "joão silva".into());
+    assert_eq!(similarity, 1.0);
+}
+
+#[test]
+fn test_reordered_words_with_typos() {
+    let me = MongeElkan::new();
+    let similarity = me.calc("brown quick fox".into(), "quikc brwn fox".into());
+    assert!(
+        similarity > 0.85 && similarity < 1.0,
+        "similarity = {}",
+        similarity
+    );
+}
+
+#[test]
+fn test_asymmetric_by_default() {
+    let me = MongeElkan::new();
+    let contained = me.calc("apple iphone pro max".into(), "iphone pro".into());
+    let reversed = me.calc("iphone pro".into(), "apple iphone pro max".into());
+    assert_eq!(contained, 1.0);
+    assert!(reversed < contained, "reversed = {}", reversed);
+}
+
+#[test]
+fn test_symmetric_variant() {
+    let me = MongeElkan::new().symmetric(true);
+    let a = me.calc("apple iphone pro max".into(), "iphone pro".into());
+    let b = me.calc("iphone pro".into(), "apple iphone pro max".into());
+    assert!((a - b).abs() < 1e-6);
+    assert!(a < 1.0, "similarity = {}", a);
+}
+
+#[test]
+fn test_custom_inner_and_name() {
+    let me = MongeElkan::new().inner(Levenshtein::new().to_box());
+    assert_eq!(me.get_algo_name(), "MongeElkan(Levenshtein)");
+    assert_eq!(me.calc("".into(), "".into()), 1.0);
+    assert_eq!(me.calc("abc".into(), "".into()), 0.0);
+}
@@ -0,0 +1,43 @@
+use nekosearch::components::prelude::*;
+
+#[test]
+fn test_identical_strings() {
+    let nw = NeedlemanWunsch::new();
+    assert_eq!(nw.calc("martha".into(), "martha".into()), 1.0);
+}
+
+#[test]
+fn test_global_alignment_covers_both_strings() {
+    let nw = NeedlemanWunsch::new();
+    let alignment = nw.align("kitten", "sitting");
+    assert_eq!(alignment.span1, 0..6);
+    assert_eq!(alignment.span2, 0..7);
+    assert_eq!(alignment.raw, 1.0);
+}
+
+#[test]
+fn test_penalizes_extra_text() {
+    let nw = NeedlemanWunsch::new().calc("apple iphone 15 pro".into(), "iphone".into());
+    let sw = SmithWaterman::new().calc("apple iphone 15 pro".into(), "iphone".into());
+    assert!(nw < sw, "nw = {}, sw = {}", nw, sw);
+}
+
+#[test]
+fn test_affine_gap() {
+    let nw = NeedlemanWunsch::new().gap_open(2.0).gap_extend(0.1);
+    let alignment = nw.align("abcdefgh", "abcdxxxefgh");
+    let expected = 8.0 - 2.0 - 0.2;
+    assert!(
+        (alignment.raw - expected).abs() < 1e-5,
+        "raw = {}",
+        alignment.raw
+    );
+}
+
+#[test]
+fn test_empty_strings() {
+    let nw = NeedlemanWunsch::new();
+    assert_eq!(nw.calc("".into(), "".into()), 1.0);
+    assert_eq!(nw.calc("abc".into(), "".into()), 0.0);
+    assert_eq!(nw.align("abc", "").raw, -2.0);
+}
@@ -69,6 +69,22 @@ fn test_custom_weights() {
     assert_ne!(result_low_lev, result_high_lev);
 }
 
+#[test]
+fn test_weights_apply_once() {
+    let mut neko = NekoSearch::new().txt("martha").term("marhta").flow(vec![
+        Jaro::new().weight(2.0).to_box(),
+        Levenshtein::new().to_box(),
+    ]);
+    let result = neko.find();
+
+    let jaro = &neko.results[0];
+    let lev = &neko.results[1];
+    assert_eq!(jaro.weight, 2.0);
+    assert!(jaro.score > lev.score);
+    let expected = (2.0 * jaro.score + lev.score) / 3.0;
+    assert!((result - expected).abs() < 1e-6);
+}
+
 #[test]
 fn test_filter_method() {
     let txt = "Rust";
@@ -230,3 +246,78 @@ fn test_completely_different_paragraphs() {
         result
     );
 }
+
+#[test]
+fn test_weight_applied_once() {
+    let mut neko = NekoSearch::new().txt("martha").term("marhta").flow(vec![
+        Jaro::new().weight(0.5).to_box(),
+        Levenshtein::new().weight(1.0).to_box(),
+    ]);
+    let result = neko.find();
+
+    let jaro = Jaro::new().calc("martha".into(), "marhta".into());
+    let lev = Levenshtein::new().calc("martha".into(), "marhta".into());
+    assert_eq!(neko.results[0].score, jaro);
+    assert_eq!(neko.results[1].score, lev);
+    assert!((result - (0.5 * jaro + lev) / 1.5).abs() < 1e-6);
+}
+
+#[test]
+fn test_weights_above_one() {
+    let mut heavy = NekoSearch::new().txt("martha").term("marhta").flow(vec![
+        Jaro::new().weight(3.0).to_box(),
+        Levenshtein::new().to_box(),
+    ]);
+    let mut capped = NekoSearch::new().txt("martha").term("marhta").flow(vec![
+        Jaro::new().weight(1.0).to_box(),
+        Levenshtein::new().to_box(),
+    ]);
+
+    assert!(heavy.find() > capped.find());
+    assert_eq!(heavy.results[0].weight, 3.0);
+}
+
+#[test]
+fn test_contributions_sum_to_score() {
+    let mut neko = NekoSearch::new().txt("Python").term("Piton").flow(vec![
+        Jaro::new().weight(2.0).to_box(),
+        Levenshtein::new().weight(0.5).to_box(),
+        Metaphone::new().to_box(),
+    ]);
+    let result = neko.find();
+    let total: f32 = neko
+        .results
+        .iter()
+        .filter_map(|find| find.contribution)
+        .sum();
+    assert!((result - total).abs() < 1e-6);
+}
+
+#[test]
+fn test_contributions_follow_the_aggregation() {
+    let flow = || vec![Jaro::new().to_box(), Levenshtein::new().to_box()];
+
+    let mut max = NekoSearch::new()
+        .txt("Python")
+        .term("Piton")
+        .flow(flow())
+        .aggregation(Aggregation::Max);
+    max.find();
+    assert!(max.results.iter().all(|find| find.contribution.is_none()));
+
+    let mut gated = NekoSearch::new()
+        .txt("Python")
+        .term("Python")
+        .flow(flow())
+        .gates(vec![Gate::exact_match()]);
+    assert_eq!(gated.find(), 1.0);
+    assert!(gated.results.iter().all(|find| find.contribution.is_none()));
+
+    let mut after = NekoSearch::new()
+        .txt("Python")
+        .term("Piton")
+        .flow(flow())
+        .gates(vec![Gate::after("low", 0.0, |e| e.score < Some(0.9))]);
+    assert_eq!(after.find(), 0.0);
+    assert!(after.results.iter().all(|find| find.contribution.is_none()));
+}
@@ -0,0 +1,36 @@
+use nekosearch::components::prelude::*;
+
+#[test]
+fn test_identical_strings() {
+    let overlap = Overlap::new();
+    let similarity = overlap.calc("martha".into(), "martha".into());
+    assert_eq!(similarity, 1.0);
+}
+
+#[test]
+fn test_short_query_contained_in_title() {
+    let overlap = Overlap::new().gram(Gram::Word);
+    let similarity = overlap.calc("apple iphone 15 pro max 256gb".into(), "iphone pro".into());
+    assert_eq!(similarity, 1.0);
+}
+
+#[test]
+fn test_contained_char_ngrams() {
+    let overlap = Overlap::new();
+    let similarity = overlap.calc("rustacean".into(), "rust".into());
+    assert_eq!(similarity, 1.0);
+}
+
+#[test]
+fn test_completely_different() {
+    let overlap = Overlap::new();
+    let similarity = overlap.calc("hello".into(), "world".into());
+    assert!(similarity < 0.4, "similarity = {}", similarity);
+}
+
+#[test]
+fn test_empty_strings() {
+    let overlap = Overlap::new();
+    assert_eq!(overlap.calc("".into(), "".into()), 1.0);
+    assert_eq!(overlap.calc("abc".into(), "".into()), 0.0);
+}
@@ -0,0 +1,61 @@
+use nekosearch::components::prelude::*;
+
+#[test]
+fn test_term_inside_text() {
+    let partial = Partial::new();
+    let txt = "apple iphone 15 pro max 256gb";
+    let found = partial.best(txt, "iphone");
+    assert_eq!(found.score, 1.0);
+    assert_eq!(found.span, 6..12);
+    assert_eq!(&txt[found.span.clone()], "iphone");
+}
+
+#[test]
+fn test_beats_whole_string_metric() {
+    let txt = "apple iphone 15 pro max 256gb";
+    let whole = Levenshtein::new().calc(txt.into(), "iphnoe".into());
+    let partial = Partial::new()
+        .inner(DamerauLevenshtein::new().to_box())
+        .calc(txt.into(), "iphnoe".into());
+    assert!(partial > whole, "partial = {}, whole = {}", partial, whole);
+    assert!(partial > 0.8, "partial = {}", partial);
+}
+
+#[test]
+fn test_argument_order_and_side() {
+    let partial = Partial::new();
+    let a = partial.best("pro max", "apple iphone pro max");
+    let b = partial.best("apple iphone pro max", "pro max");
+    assert_eq!((a.score, &a.window, &a.span), (b.score, &b.window, &b.span));
+    assert_eq!(a.window, "pro max");
+    assert_eq!(a.side, Side::Second);
+    assert_eq!(b.side, Side::First);
+}
+
+#[test]
+fn test_asymmetric_inner_keeps_order() {
+    let containment = || Tversky::new().ngram(1_usize).alpha(1.0).beta(0.0);
+    let partial = Partial::new().inner(containment().to_box());
+
+    let found = partial.best("aaz", "ab");
+    assert_eq!(found.side, Side::First);
+    assert_eq!(found.window, "aa");
+    assert_eq!(found.score, containment().calc("aa".into(), "ab".into()));
+    assert_eq!(found.score, 1.0);
+}
+
+#[test]
+fn test_multibyte_span() {
+    let partial = Partial::new();
+    let txt = "açaí com guaraná";
+    let found = partial.best(txt, "guarana");
+    assert_eq!(&txt[found.span], "guaraná");
+}
+
+#[test]
+fn test_empty_strings() {
+    let partial = Partial::new();
+    assert_eq!(partial.calc("".into(), "".into()), 1.0);
+    assert_eq!(partial.calc("abc".into(), "".into()), 0.0);
+    assert_eq!(partial.best("abc", "").span, 0..0);
+}
@@ -0,0 +1,121 @@
+use nekosearch::components::prelude::*;
+
+#[test]
+fn test_default_matches_global_normalizer() {
+    let preprocess = Preprocess::new();
+    assert_eq!(
+        preprocess.apply("The Quick, Brown Fox!"),
+        normalize("The Quick, Brown Fox!", Normalization::Nfc)
+    );
+    assert_eq!(
+        preprocess.apply("The Quick, Brown Fox!"),
+        "the quick brown fox"
+    );
+}
+
+#[test]
+fn test_letters_keep_casing() {
+    let preprocess = Preprocess::new().normalizer(Normalizer::Letters);
+    assert_eq!(
+        preprocess.apply("McDonald's 2nd-Street"),
+        "McDonald s nd Street"
+    );
+    let raw = Preprocess::new().normalizer(Normalizer::Raw);
+    assert_eq!(raw.apply(" Rust,  2024 "), " Rust,  2024 ");
+}
+
+#[test]
+fn test_field_selection_and_stop_words() {
+    let preprocess = Preprocess::new()
+        .separator('|')
+        .field(1_usize)
+        .stop_words(vec!["de".to_string(), "da".to_string()]);
+    assert_eq!(preprocess.apply("42|Maria da Silva|SP"), "maria silva");
+    assert_eq!(preprocess.apply("no separator"), "");
+}
+
+#[test]
+fn test_stop_words_ignore_unicode_case() {
+    let preprocess = Preprocess::new()
+        .normalizer(Normalizer::Raw)
+        .stop_words(vec!["Über".to_string(), "ÉS".to_string()]);
+    assert_eq!(preprocess.apply("ÜBER Straße és Platz"), "Straße Platz");
+}
+
+#[test]
+fn test_custom_normalizer_and_tokenizer() {
+    let preprocess = Preprocess::new()
+        .normalizer(Normalizer::Custom(Transform::new(|s| s.replace('-', " "))))
+        .tokenizer(Tokenizer::Custom(Split::new(|s| {
+            let mut tokens: Vec<String> = s.split_whitespace().map(String::from).collect();
+            tokens.sort();
+            tokens
+        })));
+    assert_eq!(preprocess.apply("fox-brown quick"), "brown fox quick");
+}
+
+#[test]
+fn test_prepared_outside_search() {
+    let prepared = Prepared::new()
+        .inner(Jaccard::new().to_box())
+        .preprocess(Preprocess::new().stop_words(vec!["the".to_string()]));
+    let score = prepared.calc("The Rust Book".into(), "rust book".into());
+    assert_eq!(score, 1.0);
+    assert_eq!(prepared.get_name(), "Jaccard");
+}
+
+#[test]
+fn test_per_algorithm_preprocessing_in_flow() {
+    let mut neko = NekoSearch::new()
+        .txt("The Rust Programming Language")
+        .term("rust programming language")
+        .flow(vec![
+            Levenshtein::new().to_box(),
+            Prepared::new()
+                .inner(Jaccard::new().to_box())
+                .preprocess(Preprocess::new().stop_words(vec!["the".to_string()]))
+                .to_box(),
+            Prepared::new()
+                .inner(Metaphone::new().to_box())
+                .preprocess(Preprocess::new().normalizer(Normalizer::Letters))
+                .to_box(),
+        ]);
+    neko.find();
+
+    let lev = &neko.results[0];
+    assert_eq!(lev.txt, "the rust programming language");
+    assert!(lev.score < 1.0);
+
+    let jaccard = &neko.results[1];
+    assert_eq!(jaccard.txt, "rust programming language");
+    assert_eq!(jaccard.score, 1.0);
+
+    let metaphone = &neko.results[2];
+    assert_eq!(metaphone.algo, "Metaphone");
+    assert_eq!(metaphone.txt, "The Rust Programming Language");
+}
+
+#[test]
+fn test_prepared_runs_preprocess_once_in_flow() {
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    let calls = Arc::new(AtomicUsize::new(0));
+    let counter = calls.clone();
+    let prepared =
+        Prepared::new()
+            .inner(Jaccard::new().to_box())
+            .preprocess(
+                Preprocess::new().normalizer(Normalizer::Custom(Transform::new(move |s| {
+                    counter.fetch_add(1, Ordering::SeqCst);
+                    s.to_lowercase()
+                }))),
+            );
+    let mut neko = NekoSearch::new()
+        .txt("Rust Book")
+        .term("rust book")
+        .flow(vec![Weighted::new().inner(prepared.to_box()).to_box()]);
+
+    assert_eq!(neko.find(), 1.0);
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+}
@@ -0,0 +1,94 @@
+use nekosearch::components::prelude::*;
+
+// Expected values recorded from Python 3 `difflib.SequenceMatcher`.
+
+fn assert_close(actual: f32, expected: f64) {
+    assert!(
+        (actual as f64 - expected).abs() < 1e-6,
+        "actual = {}, expected = {}",
+        actual,
+        expected
+    );
+}
+
+fn blocks(list: &[(usize, usize, usize)]) -> Vec<MatchingBlock> {
+    list.iter()
+        .map(|&(a, b, size)| MatchingBlock { a, b, size })
+        .collect()
+}
+
+#[test]
+fn test_difflib_ratio_corpus() {
+    let ro = RatcliffObershelp::new();
+    let corpus = [
+        ("martha", "marhta", 0.8333333333333334),
+        ("kitten", "sitting", 0.6153846153846154),
+        ("GATTACA", "GCATAG", 0.6153846153846154),
+        (
+            "the quick brown fox",
+            "the quikc brown fx",
+            0.918918918918919,
+        ),
+        ("abcd", "bcde", 0.75),
+        ("", "abc", 0.0),
+        ("", "", 1.0),
+    ];
+    for (a, b, expected) in corpus {
+        assert_close(ro.calc(a.into(), b.into()), expected);
+    }
+}
+
+#[test]
+fn test_difflib_parity_on_combining_marks_and_crlf() {
+    let ro = RatcliffObershelp::new();
+    assert_close(ro.calc("a\r\nb".into(), "a\nb".into()), 0.8571428571428571);
+    assert_close(
+        ro.calc("cafe\u{301}".into(), "cafe".into()),
+        0.8888888888888888,
+    );
+
+    let graphemes = RatcliffObershelp::new()
+        .segmentation(Segmentation::Graphemes)
+        .unicode(Normalization::Nfc);
+    assert_close(graphemes.calc("a\r\nb".into(), "a\nb".into()), 2.0 / 3.0);
+}
+
+#[test]
+fn test_matching_blocks() {
+    let ro = RatcliffObershelp::new();
+    assert_eq!(
+        ro.matching_blocks("martha", "marhta"),
+        blocks(&[(0, 0, 3), (3, 4, 1), (5, 5, 1), (6, 6, 0)])
+    );
+    assert_eq!(
+        ro.matching_blocks("GATTACA", "GCATAG"),
+        blocks(&[(0, 0, 1), (1, 2, 2), (4, 4, 1), (7, 6, 0)])
+    );
+}
+
+#[test]
+fn test_junk_predicate() {
+    let a = "private Thread currentThread;";
+    let b = "private volatile Thread currentThread;";
+    let plain = RatcliffObershelp::new();
+    let junk = RatcliffObershelp::new().junk(Junk::new(|c| c == ' '));
+    assert_eq!(
+        plain.matching_blocks(a, b),
+        blocks(&[(0, 0, 6), (6, 15, 23), (29, 38, 0)])
+    );
+    assert_eq!(
+        junk.matching_blocks(a, b),
+        blocks(&[(0, 0, 8), (8, 17, 21), (29, 38, 0)])
+    );
+    assert_close(junk.ratio(a, b), 0.8656716417910447);
+}
+
+#[test]
+fn test_autojunk_heuristic() {
+    let a = format!("{}xyz", "ab".repeat(150));
+    let b = format!("{}xyz", "ba".repeat(150));
+    let auto = RatcliffObershelp::new().ratio(&a, &b);
+    let manual = RatcliffObershelp::new().autojunk(false).ratio(&a, &b);
+    assert_close(auto, 0.009900990099009901);
+    assert_close(manual, 0.9966996699669967);
+}
@@ -0,0 +1,61 @@
+use nekosearch::components::prelude::*;
+
+#[test]
+fn test_identical_strings() {
+    let sw = SmithWaterman::new();
+    assert_eq!(sw.calc("martha".into(), "martha".into()), 1.0);
+}
+
+#[test]
+fn test_local_region() {
+    let sw = SmithWaterman::new();
+    let txt = "apple iphone 15 pro";
+    let alignment = sw.align(txt, "iphone");
+    assert_eq!(alignment.score, 1.0);
+    assert_eq!(alignment.raw, 6.0);
+    assert_eq!(&txt[alignment.span1], "iphone");
+    assert_eq!(alignment.span2, 0..6);
+}
+
+#[test]
+fn test_affine_gap() {
+    let sw = SmithWaterman::new().gap_open(2.0).gap_extend(0.1);
+    let alignment = sw.align("abcdefgh", "abcdxxxefgh");
+    let expected = 8.0 - 2.0 - 0.2;
+    assert!(
+        (alignment.raw - expected).abs() < 1e-5,
+        "raw = {}",
+        alignment.raw
+    );
+    assert_eq!(alignment.span2, 0..11);
+}
+
+#[test]
+fn test_custom_substitution() {
+    let sw = SmithWaterman::new().substitution(Substitution::new(|a, b| {
+        if a.eq_ignore_ascii_case(&b) {
+            2.0
+        } else {
+            -2.0
+        }
+    }));
+    let alignment = sw.align("xxRUSTxx", "rust");
+    assert_eq!(alignment.raw, 8.0);
+    assert_eq!(alignment.span1, 2..6);
+}
+
+#[test]
+fn test_multibyte_span() {
+    let sw = SmithWaterman::new();
+    let txt = "suco de açaí gelado";
+    let alignment = sw.align(txt, "açaí");
+    assert_eq!(&txt[alignment.span1], "açaí");
+}
+
+#[test]
+fn test_no_match_and_empty() {
+    let sw = SmithWaterman::new();
+    assert_eq!(sw.calc("abc".into(), "xyz".into()), 0.0);
+    assert_eq!(sw.calc("".into(), "".into()), 1.0);
+    assert_eq!(sw.calc("abc".into(), "".into()), 0.0);
+}
@@ -0,0 +1,63 @@
+use nekosearch::components::prelude::*;
+
+const CORPUS: [&str; 6] = [
+    "acme comercio ltda",
+    "bravo comercio ltda",
+    "delta servicos ltda",
+    "omega comercio e servicos ltda",
+    "acme industria sa",
+    "zeta comercio e servicos",
+];
+
+#[test]
+fn test_identical_strings() {
+    let soft = SoftTfIdf::new().fit(&CORPUS);
+    let similarity = soft.calc("acme comercio ltda".into(), "acme comercio ltda".into());
+    assert!(
+        (similarity - 1.0).abs() < 1e-5,
+        "similarity = {}",
+        similarity
+    );
+}
+
+#[test]
+fn test_rare_tokens_dominate() {
+    let soft = SoftTfIdf::new().fit(&CORPUS);
+    let rare = soft.calc("acme comercio ltda".into(), "acme".into());
+    let common = soft.calc("acme comercio ltda".into(), "ltda".into());
+    assert!(rare > common, "rare = {}, common = {}", rare, common);
+}
+
+#[test]
+fn test_typo_tolerance() {
+    let soft = SoftTfIdf::new().fit(&CORPUS);
+    let similarity = soft.calc(
+        "Acme Comercio Ltda".into(),
+        "ACME Comércio e Serviços".into(),
+    );
+    let strict = SoftTfIdf::new().threshold(1.0).fit(&CORPUS).calc(
+        "Acme Comercio Ltda".into(),
+        "ACME Comércio e Serviços".into(),
+    );
+    assert!(
+        similarity > strict,
+        "soft = {}, strict = {}",
+        similarity,
+        strict
+    );
+    assert!(similarity > 0.5, "similarity = {}", similarity);
+}
+
+#[test]
+fn test_idf_weights() {
+    let soft = SoftTfIdf::new().fit(&CORPUS);
+    assert!(soft.idf("acme") > soft.idf("ltda"));
+    assert!(soft.idf("unseen") > soft.idf("acme"));
+}
+
+#[test]
+fn test_empty_strings() {
+    let soft = SoftTfIdf::new().fit(&CORPUS);
+    assert_eq!(soft.calc("".into(), "".into()), 1.0);
+    assert_eq!(soft.calc("abc".into(), "".into()), 0.0);
+}
@@ -0,0 +1,44 @@
+use nekosearch::components::prelude::*;
+
+#[test]
+fn test_identical_strings() {
+    let dice = SorensenDice::new();
+    let similarity = dice.calc("martha".into(), "martha".into());
+    assert_eq!(similarity, 1.0);
+}
+
+#[test]
+fn test_known_value() {
+    let dice = SorensenDice::new();
+    let similarity = dice.calc("night".into(), "nacht".into());
+    assert!(
+        (similarity - 0.25).abs() < 1e-6,
+        "similarity = {}",
+        similarity
+    );
+}
+
+#[test]
+fn test_greater_or_equal_than_jaccard() {
+    let dice = SorensenDice::new().calc("martha".into(), "marsha".into());
+    let jaccard = Jaccard::new().calc("martha".into(), "marsha".into());
+    assert!(dice >= jaccard, "dice = {}, jaccard = {}", dice, jaccard);
+}
+
+#[test]
+fn test_word_tokens() {
+    let dice = SorensenDice::new().gram(Gram::Word);
+    let similarity = dice.calc("joão da silva".into(), "silva joão".into());
+    assert!(
+        (similarity - 0.8).abs() < 1e-6,
+        "similarity = {}",
+        similarity
+    );
+}
+
+#[test]
+fn test_empty_strings() {
+    let dice = SorensenDice::new();
+    assert_eq!(dice.calc("".into(), "".into()), 1.0);
+    assert_eq!(dice.calc("abc".into(), "".into()), 0.0);
+}
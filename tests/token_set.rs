@@ -0,0 +1,46 @@
+use nekosearch::components::prelude::*;
+
+#[test]
+fn test_reordered_words() {
+    let token_set = TokenSet::new();
+    let similarity = token_set.calc("silva joão".into(), "joão silva".into());
+    assert_eq!(similarity, 1.0);
+}
+
+#[test]
+fn test_subset_of_words() {
+    let token_set = TokenSet::new();
+    let similarity = token_set.calc("maria da silva santos".into(), "santos maria".into());
+    assert_eq!(similarity, 1.0);
+}
+
+#[test]
+fn test_duplicated_words() {
+    let token_set = TokenSet::new();
+    let similarity = token_set.calc("new york new york".into(), "york new".into());
+    assert_eq!(similarity, 1.0);
+}
+
+#[test]
+fn test_no_common_words() {
+    let token_set = TokenSet::new().inner(Jaccard::new().to_box());
+    let similarity = token_set.calc("hello there".into(), "world peace".into());
+    assert!(similarity < 0.4, "similarity = {}", similarity);
+}
+
+#[test]
+fn test_empty_strings() {
+    let token_set = TokenSet::new();
+    assert_eq!(token_set.calc("".into(), "".into()), 1.0);
+    assert_eq!(token_set.calc("abc".into(), "".into()), 0.0);
+}
+
+#[test]
+fn test_inside_flow() {
+    let mut neko = NekoSearch::new()
+        .txt("Silva, João")
+        .term("João Silva")
+        .flow(vec![TokenSet::new().inner(Jaro::new().to_box()).to_box()]);
+    assert_eq!(neko.find(), 1.0);
+    assert_eq!(neko.results[0].algo, "TokenSet(Jaro)");
+}
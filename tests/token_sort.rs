@@ -0,0 +1,35 @@
+use nekosearch::components::prelude::*;
+
+#[test]
+fn test_reordered_words() {
+    let token_sort = TokenSort::new();
+    let similarity = token_sort.calc("silva joão".into(), "joão silva".into());
+    assert_eq!(similarity, 1.0);
+}
+
+#[test]
+fn test_improves_inner_algorithm() {
+    let plain = Lcs::new().calc("brown quick fox".into(), "quick brown fox".into());
+    let sorted = TokenSort::new()
+        .inner(Lcs::new().to_box())
+        .calc("brown quick fox".into(), "quick brown fox".into());
+    assert!(sorted > plain, "sorted = {}, plain = {}", sorted, plain);
+    assert_eq!(sorted, 1.0);
+}
+
+#[test]
+fn test_typo_after_sorting() {
+    let token_sort = TokenSort::new().inner(Jaro::new().to_box());
+    let similarity = token_sort.calc("silva joão".into(), "joao silva".into());
+    assert!(
+        similarity > 0.8 && similarity < 1.0,
+        "similarity = {}",
+        similarity
+    );
+}
+
+#[test]
+fn test_algo_name() {
+    let token_sort = TokenSort::new().inner(Jaro::new().to_box());
+    assert_eq!(token_sort.get_algo_name(), "TokenSort(Jaro)");
+}
@@ -0,0 +1,133 @@
+use nekosearch::components::prelude::*;
+
+fn pairs() -> Vec<(&'static str, &'static str, bool)> {
+    let matches = [
+        ("Jonathan Smith", "Jonathon Smith"),
+        ("Maria da Silva", "Maria Silva"),
+        ("Catherine", "Katherine"),
+        ("Mohammed Ali", "Muhammad Ali"),
+        ("Stephen King", "Steven King"),
+        ("Rust", "Rustt"),
+        ("Python", "Pyhton"),
+        ("development", "developement"),
+        ("martha", "marhta"),
+        ("New York", "New Yrok"),
+        ("São Paulo", "Sao Paulo"),
+        ("Philip", "Phillip"),
+        ("Jefferson", "Jeferson"),
+        ("Washington", "Washingtn"),
+        ("Christopher", "Kristopher"),
+        ("Elizabeth", "Elisabeth"),
+    ];
+    let non_matches = [
+        ("Jonathan Smith", "Maria Silva"),
+        ("Catherine", "Robert"),
+        ("Rust", "Java"),
+        ("Python", "Haskell"),
+        ("development", "banana"),
+        ("martha", "george"),
+        ("New York", "Los Angeles"),
+        ("São Paulo", "Rio de Janeiro"),
+        ("Philip", "Amanda"),
+        ("Jefferson", "Lincoln"),
+        ("Washington", "Oregon"),
+        ("Christopher", "Benjamin"),
+        ("Elizabeth", "Margaret"),
+        ("Stephen King", "Agatha Christie"),
+        ("Mohammed Ali", "Mike Tyson"),
+        ("Kitten", "Sitting Bull"),
+    ];
+    matches
+        .iter()
+        .map(|&(a, b)| (a, b, true))
+        .chain(non_matches.iter().map(|&(a, b)| (a, b, false)))
+        .collect()
+}
+
+#[test]
+fn test_fit_default_flow() {
+    let trained = Trainer::new().fit(&pairs());
+    assert!(!trained.weights.is_empty() && trained.weights.len() <= 7);
+    assert!(trained.weights.iter().all(|&(_, w)| w > 0.0));
+    assert!(trained.threshold > 0.0 && trained.threshold < 1.0);
+    assert!(
+        trained.precision.is_some_and(|p| p >= 0.8),
+        "precision = {:?}",
+        trained.precision
+    );
+    assert!(
+        trained.recall.is_some_and(|r| r >= 0.8),
+        "recall = {:?}",
+        trained.recall
+    );
+    assert!(trained.weights.iter().any(|&(_, w)| w == 1.0));
+}
+
+#[test]
+fn test_trained_search_is_ready_to_use() {
+    let mut trained = Trainer::new().fit(&pairs());
+    assert!(trained.neko.txt.is_empty() && trained.neko.term.is_empty());
+    assert!(trained.neko.results.is_empty());
+    assert!(trained.is_match("Jennifer Lopez", "Jenifer Lopez"));
+    assert!(!trained.is_match("Jennifer Lopez", "Tom Hanks"));
+    assert!(trained.neko.results.is_empty());
+
+    trained.neko.txt = "Jennifer Lopez".to_string();
+    trained.neko.term = "Jenifer Lopez".to_string();
+    assert!(trained.neko.find() >= trained.threshold);
+
+    assert!(matches!(
+        trained.neko.aggregation,
+        Aggregation::WeightedMean
+    ));
+    assert_eq!(trained.neko.results.len(), trained.weights.len());
+    for (find, (name, weight)) in trained.neko.results.iter().zip(&trained.weights) {
+        assert_eq!(&find.algo, name);
+        assert_eq!(find.weight, *weight);
+    }
+}
+
+#[test]
+fn test_misleading_feature_gets_no_weight() {
+    let trained = Trainer::new()
+        .flow(vec![
+            Levenshtein::new().to_box(),
+            Closure::named("inverse", |s1, s2| {
+                1.0 - Levenshtein::new().calc(s1.into(), s2.into())
+            })
+            .to_box(),
+        ])
+        .fit(&pairs());
+    assert_eq!(trained.weights, [("Levenshtein".to_string(), 1.0)]);
+    assert_eq!(trained.neko.flow.len(), 1);
+}
+
+#[test]
+fn test_split_is_deterministic() {
+    let a = Trainer::new().seed(7_u64).fit(&pairs());
+    let b = Trainer::new().seed(7_u64).fit(&pairs());
+    assert_eq!(a.weights, b.weights);
+    assert_eq!(a.threshold, b.threshold);
+}
+
+#[test]
+fn test_weighted_passes_through() {
+    let inner = Prepared::new()
+        .inner(Jaccard::new().to_box())
+        .preprocess(Preprocess::new().stop_words(vec!["the".to_string()]));
+    let weighted = Weighted::new().inner(inner.to_box()).weight(2.5);
+    assert_eq!(weighted.get_weight(), 2.5);
+    assert_eq!(weighted.get_name(), "Jaccard");
+    assert_eq!(
+        weighted.prepare("The Book", "book"),
+        Some(("book".to_string(), "book".to_string()))
+    );
+}
+
+#[test]
+fn test_empty_training_set() {
+    let trained = Trainer::new().fit::<&str>(&[]);
+    assert_eq!(trained.weights.len(), 7);
+    assert_eq!(trained.threshold, 0.5);
+    assert_eq!((trained.precision, trained.recall), (None, None));
+}
@@ -0,0 +1,60 @@
+use nekosearch::components::prelude::*;
+
+fn assert_close(actual: f32, expected: f32) {
+    assert!(
+        (actual - expected).abs() < 1e-6,
+        "actual = {}, expected = {}",
+        actual,
+        expected
+    );
+}
+
+#[test]
+fn test_show_trgm() {
+    assert_eq!(show_trgm("word"), vec!["  w", " wo", "ord", "rd ", "wor"]);
+    assert_eq!(show_trgm("a-b").len(), 4);
+    assert!(show_trgm("---").is_empty());
+}
+
+#[test]
+fn test_similarity_vectors() {
+    assert_close(trgm_similarity("word", "two words"), 0.363636);
+    assert_close(trgm_similarity("wow", "WOWa "), 0.5);
+    assert_close(trgm_similarity("wow", " WOW "), 1.0);
+    assert_close(trgm_similarity("---", "####---"), 0.0);
+}
+
+#[test]
+fn test_word_similarity_vectors() {
+    assert_close(trgm_word_similarity("word", "two words"), 0.8);
+    assert_close(trgm_word_similarity("wow", "WOWa "), 0.75);
+}
+
+#[test]
+fn test_strict_word_similarity_vectors() {
+    assert_close(trgm_strict_word_similarity("word", "two words"), 0.571429);
+    assert_close(trgm_strict_word_similarity("wow", " WOW "), 1.0);
+}
+
+#[test]
+fn test_calc_modes() {
+    let txt = String::from("two words");
+    let term = String::from("word");
+    let similarity = Trigram::new().calc(txt.clone(), term.clone());
+    let word = Trigram::new()
+        .mode(TrigramMode::WordSimilarity)
+        .calc(txt.clone(), term.clone());
+    let strict = Trigram::new()
+        .mode(TrigramMode::StrictWordSimilarity)
+        .calc(txt, term);
+    assert_close(similarity, 0.363636);
+    assert_close(word, 0.8);
+    assert_close(strict, 0.571429);
+}
+
+#[test]
+fn test_empty_strings() {
+    let trigram = Trigram::new();
+    assert_eq!(trigram.calc("".into(), "".into()), 0.0);
+    assert_eq!(trigram.calc("abc".into(), "".into()), 0.0);
+}
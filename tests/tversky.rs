@@ -0,0 +1,41 @@
+use nekosearch::components::prelude::*;
+
+#[test]
+fn test_identical_strings() {
+    let tversky = Tversky::new();
+    let similarity = tversky.calc("martha".into(), "martha".into());
+    assert_eq!(similarity, 1.0);
+}
+
+#[test]
+fn test_defaults_match_sorensen_dice() {
+    let tversky = Tversky::new().calc("martha".into(), "marsha".into());
+    let dice = SorensenDice::new().calc("martha".into(), "marsha".into());
+    assert!((tversky - dice).abs() < 1e-6);
+}
+
+#[test]
+fn test_unit_parameters_match_jaccard() {
+    let tversky = Tversky::new()
+        .alpha(1.0)
+        .beta(1.0)
+        .calc("the quick brown fox".into(), "the quick brown dog".into());
+    let jaccard = Jaccard::new().calc("the quick brown fox".into(), "the quick brown dog".into());
+    assert!((tversky - jaccard).abs() < 1e-6);
+}
+
+#[test]
+fn test_asymmetric_containment() {
+    let tversky = Tversky::new().gram(Gram::Word).alpha(0.0).beta(1.0);
+    let contained = tversky.calc("apple iphone 15 pro max".into(), "iphone pro".into());
+    let reversed = tversky.calc("iphone pro".into(), "apple iphone 15 pro max".into());
+    assert_eq!(contained, 1.0);
+    assert!((reversed - 0.4).abs() < 1e-6, "similarity = {}", reversed);
+}
+
+#[test]
+fn test_empty_strings() {
+    let tversky = Tversky::new();
+    assert_eq!(tversky.calc("".into(), "".into()), 1.0);
+    assert_eq!(tversky.calc("abc".into(), "".into()), 0.0);
+}
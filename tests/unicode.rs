@@ -0,0 +1,124 @@
+use nekosearch::components::prelude::*;
+use nekosearch::components::unicode::{normalize_unicode, segment};
+
+const COMBINED: &str = "cafe\u{301}";
+const FAMILY: &str = "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}";
+
+#[test]
+fn test_segment() {
+    assert_eq!(segment(COMBINED, Segmentation::Graphemes).len(), 4);
+    assert_eq!(segment(COMBINED, Segmentation::Chars).len(), 5);
+    assert_eq!(segment(FAMILY, Segmentation::Graphemes), vec![FAMILY]);
+    assert_eq!(segment("🇧🇷🇵🇹", Segmentation::Graphemes).len(), 2);
+}
+
+#[test]
+fn test_normalize_unicode() {
+    assert_eq!(normalize_unicode(COMBINED, Normalization::Nfc), "café");
+    assert_eq!(normalize_unicode("ﬁ", Normalization::Nfc), "ﬁ");
+    assert_eq!(normalize_unicode("ﬁ", Normalization::Nfkc), "fi");
+    assert_eq!(normalize_unicode(COMBINED, Normalization::None), COMBINED);
+}
+
+#[test]
+fn test_graphemes_are_the_default_unit() {
+    let graphemes = Levenshtein::new()
+        .unicode(Normalization::None)
+        .calc(COMBINED.into(), "cafe".into());
+    let chars = Levenshtein::new()
+        .unicode(Normalization::None)
+        .segmentation(Segmentation::Chars)
+        .calc(COMBINED.into(), "cafe".into());
+    assert_eq!(graphemes, 0.75);
+    assert_eq!(chars, 0.8);
+}
+
+#[test]
+fn test_emoji_count_as_one_unit() {
+    let flags = Levenshtein::new().calc("vamos 🇧🇷".into(), "vamos 🇵🇹".into());
+    assert!((flags - 6.0 / 7.0).abs() < 1e-6, "similarity = {}", flags);
+
+    let family = DamerauLevenshtein::new().calc(format!("a{FAMILY}b"), format!("b{FAMILY}a"));
+    assert!((family - 1.0 / 3.0).abs() < 1e-6, "similarity = {}", family);
+
+    let bigrams = Jaccard::new().calc(format!("{FAMILY}!"), format!("{FAMILY}?"));
+    assert_eq!(bigrams, 0.0);
+}
+
+#[test]
+fn test_every_char_scorer_uses_graphemes() {
+    let txt = String::from("ab🇧🇷");
+    let term = String::from("ab🇵🇹");
+    let flow: Vec<Box<dyn Calc>> = vec![
+        Levenshtein::new().to_box(),
+        DamerauLevenshtein::new().to_box(),
+        Lcs::new().to_box(),
+        Hamming::new().to_box(),
+        RatcliffObershelp::new()
+            .segmentation(Segmentation::Graphemes)
+            .to_box(),
+        NeedlemanWunsch::new()
+            .match_score(1.0)
+            .mismatch_score(0.0)
+            .to_box(),
+    ];
+    for algo in flow {
+        let score = algo.calc(txt.clone(), term.clone());
+        assert!(
+            (score - 2.0 / 3.0).abs() < 1e-6,
+            "{} = {}",
+            algo.get_algo_name(),
+            score
+        );
+    }
+}
+
+#[test]
+fn test_neko_search_normalizer() {
+    let mut nfc = NekoSearch::new().txt("Café").term(COMBINED);
+    assert_eq!(nfc.find(), 1.0);
+
+    let mut raw = NekoSearch::new()
+        .txt("Café")
+        .term(COMBINED)
+        .unicode(Normalization::None)
+        .flow(vec![
+            Levenshtein::new().unicode(Normalization::None).to_box(),
+            Jaro::new().unicode(Normalization::None).to_box(),
+        ]);
+    let score = raw.find();
+    assert!(score < 1.0 && score > 0.5, "score = {}", score);
+}
+
+#[test]
+fn test_direct_calc_normalizes() {
+    let (decomposed, composed) = ("e\u{301}", "\u{e9}");
+    let flow: Vec<Box<dyn Calc>> = vec![
+        Levenshtein::new().to_box(),
+        DamerauLevenshtein::new().to_box(),
+        Jaro::new().to_box(),
+        Lcs::new().to_box(),
+        Jaccard::new().to_box(),
+        Cosine::new().to_box(),
+        SorensenDice::new().to_box(),
+        Overlap::new().to_box(),
+        Tversky::new().to_box(),
+        Hamming::new().to_box(),
+        Editex::new().to_box(),
+        Partial::new().to_box(),
+        SmithWaterman::new().to_box(),
+        NeedlemanWunsch::new().to_box(),
+        RatcliffObershelp::new()
+            .unicode(Normalization::Nfc)
+            .to_box(),
+    ];
+    for algo in flow {
+        let score = algo.calc(decomposed.into(), composed.into());
+        assert_eq!(score, 1.0, "{}", algo.get_algo_name());
+    }
+
+    let raw = Levenshtein::new().unicode(Normalization::None);
+    assert_eq!(raw.calc(decomposed.into(), composed.into()), 0.0);
+    let nfkc = Levenshtein::new().unicode(Normalization::Nfkc);
+    assert_eq!(nfkc.calc("ﬁ".into(), "fi".into()), 1.0);
+}
@@ -0,0 +1,54 @@
+use nekosearch::components::prelude::*;
+
+#[test]
+fn test_identical_sentences() {
+    let wl = WordLevenshtein::new();
+    let similarity = wl.calc("the quick brown fox".into(), "the quick brown fox".into());
+    assert_eq!(similarity, 1.0);
+}
+
+#[test]
+fn test_counts_changed_words() {
+    let wl = WordLevenshtein::new();
+    let similarity = wl.calc("the quick brown fox".into(), "the quikc brown fx".into());
+    assert_eq!(similarity, 0.5);
+}
+
+#[test]
+fn test_partial_substitution_cost() {
+    let wl = WordLevenshtein::new().inner(Jaro::new().to_box());
+    let similarity = wl.calc("the quick brown fox".into(), "the quikc brown fx".into());
+    assert!(
+        similarity > 0.85 && similarity < 1.0,
+        "similarity = {}",
+        similarity
+    );
+    let unrelated = wl.calc("the quick brown fox".into(), "a lazy sleeping dog".into());
+    assert!(unrelated < 0.3, "similarity = {}", unrelated);
+}
+
+#[test]
+fn test_inserted_word() {
+    let wl = WordLevenshtein::new();
+    let similarity = wl.calc(
+        "the quick brown fox".into(),
+        "the quick brown fox jumps".into(),
+    );
+    assert_eq!(similarity, 0.8);
+}
+
+#[test]
+fn test_inside_flow() {
+    let mut neko = NekoSearch::new()
+        .txt("The quick brown fox")
+        .term("The quikc brown fx")
+        .flow(vec![
+            WordLevenshtein::new()
+                .inner(Levenshtein::new().to_box())
+                .to_box(),
+        ]);
+    let score = neko.find();
+    assert!(score > 0.7, "score = {}", score);
+    assert_eq!(neko.results[0].algo, "WordLevenshtein(Levenshtein)");
+    assert_eq!(WordLevenshtein::new().calc("".into(), "".into()), 1.0);
+}